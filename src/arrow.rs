@@ -0,0 +1,306 @@
+#![warn(dead_code)]
+// External imports
+use ::arrow::array::Decimal128Array;
+use ::arrow::array::Decimal256Array;
+use ::arrow::datatypes::i256;
+use ::arrow::datatypes::validate_decimal_precision_and_scale;
+use ::arrow::datatypes::Decimal128Type;
+use ::arrow::datatypes::Decimal256Type;
+use ::arrow::datatypes::DecimalType;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// What a conversion to an Arrow decimal array does with a value that has
+/// more digits than the precision allows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OnOverflow {
+    /// Stop and return [MathErrors::Overflow].
+    Error,
+    /// Store a null in place of the value.
+    Null,
+}
+
+/// Returns the digits of x multiplied by 10^scale.
+///
+/// [MathErrors::PrecisionLoss] is returned if that is not an integer and
+/// [MathErrors::Overflow] if it has more than precision digits.
+fn unscaled(x: &BigNumber, precision: u8, scale: i8) -> Result<Vec<Digits>, MathErrors> {
+    let shifted: BigNumber = x.shift_decimal(scale as isize);
+    if !shifted.is_integer() {
+        return Err(MathErrors::PrecisionLoss);
+    }
+    let digits: &[Digits] = magnitude::significant(&shifted.integer);
+    if digits.len() > precision as usize {
+        return Err(MathErrors::Overflow);
+    }
+    return Ok(digits.to_vec());
+}
+
+/// Converts every value with [unscaled], then builds the array from the
+/// results with to_native.
+fn to_natives<T: DecimalType>(
+    values: &[BigNumber],
+    precision: u8,
+    scale: i8,
+    overflow: OnOverflow,
+    to_native: fn(bool, &[Digits]) -> T::Native,
+) -> Result<Vec<Option<T::Native>>, MathErrors> {
+    if validate_decimal_precision_and_scale::<T>(precision, scale).is_err() {
+        return Err(MathErrors::DomainError);
+    }
+    let mut result: Vec<Option<T::Native>> = Vec::with_capacity(values.len());
+    for x in values {
+        match unscaled(x, precision, scale) {
+            Ok(digits) => result.push(Some(to_native(x.is_negative(), &digits))),
+            Err(MathErrors::Overflow) if overflow == OnOverflow::Null => result.push(None),
+            Err(error) => return Err(error),
+        }
+    }
+    return Ok(result);
+}
+
+/// Returns value divided by 10^scale.
+fn from_unscaled(sign: Sign, digits: Vec<Digits>, scale: i8) -> BigNumber {
+    if scale < 0 {
+        return BigNumber::from_coefficient(sign, digits, 0).shift_decimal(-scale as isize);
+    }
+    return BigNumber::from_coefficient(sign, digits, scale as usize);
+}
+
+// Conversions between slices of BigNumber and Arrow decimal arrays.
+//
+// Values are stored multiplied by 10^scale, so every value is padded with
+// zeros to the scale. Arrow allows negative scales, which store values
+// divided by a power of ten.
+impl BigNumber {
+    /// Builds a Decimal128Array with the precision and scale.
+    ///
+    /// [MathErrors::DomainError] is returned if Arrow does not allow the
+    /// precision and scale and [MathErrors::PrecisionLoss] if a value has
+    /// nonzero digits beyond the scale. A value with too many digits for the
+    /// precision returns [MathErrors::Overflow] or becomes a null, depending
+    /// on overflow.
+    ///
+    /// The error does not say which value caused it. Converting again with
+    /// [OnOverflow::Null] leaves a null at exactly the values that overflow.
+    pub fn to_decimal128_array(
+        values: &[BigNumber],
+        precision: u8,
+        scale: i8,
+        overflow: OnOverflow,
+    ) -> Result<Decimal128Array, MathErrors> {
+        // At most 38 digits, which always fits.
+        let to_native = |negative: bool, digits: &[Digits]| -> i128 {
+            let value: i128 = magnitude::to_u128(digits).unwrap() as i128;
+            return if negative { -value } else { value };
+        };
+        let natives = to_natives::<Decimal128Type>(values, precision, scale, overflow, to_native)?;
+        return Ok(Decimal128Array::from(natives)
+            .with_precision_and_scale(precision, scale)
+            .unwrap());
+    }
+
+    /// Builds a Decimal256Array with the precision and scale, see
+    /// [BigNumber::to_decimal128_array].
+    pub fn to_decimal256_array(
+        values: &[BigNumber],
+        precision: u8,
+        scale: i8,
+        overflow: OnOverflow,
+    ) -> Result<Decimal256Array, MathErrors> {
+        // At most 76 digits, which always fits.
+        let to_native = |negative: bool, digits: &[Digits]| -> i256 {
+            let ten: i256 = i256::from_i128(10);
+            let value: i256 = digits.iter().fold(i256::ZERO, |total, d| {
+                total
+                    .wrapping_mul(ten)
+                    .wrapping_add(i256::from_i128(d.to_usize() as i128))
+            });
+            return if negative {
+                value.wrapping_neg()
+            } else {
+                value
+            };
+        };
+        let natives = to_natives::<Decimal256Type>(values, precision, scale, overflow, to_native)?;
+        return Ok(Decimal256Array::from(natives)
+            .with_precision_and_scale(precision, scale)
+            .unwrap());
+    }
+
+    /// Reads every value of a Decimal128Array, with None for the nulls.
+    ///
+    /// The values keep the scale of the array, so 150 with a scale of 2 is
+    /// 1.50.
+    pub fn from_decimal128_array(array: &Decimal128Array) -> Vec<Option<BigNumber>> {
+        let scale: i8 = array.scale();
+        return array
+            .iter()
+            .map(|value| {
+                let value: i128 = value?;
+                let sign: Sign = if value < 0 {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+                let digits: Vec<Digits> = magnitude::from_u128(value.unsigned_abs());
+                return Some(from_unscaled(sign, digits, scale));
+            })
+            .collect();
+    }
+
+    /// Reads every value of a Decimal256Array, with None for the nulls, see
+    /// [BigNumber::from_decimal128_array].
+    pub fn from_decimal256_array(array: &Decimal256Array) -> Vec<Option<BigNumber>> {
+        let scale: i8 = array.scale();
+        return array
+            .iter()
+            .map(|value| {
+                let value: i256 = value?;
+                let sign: Sign = if value.is_negative() {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+                // Arrow does not check values against the precision, so
+                // i256::MIN can turn up. Its wrapping_abs is itself, whose
+                // bytes read as unsigned are 2^255, its magnitude.
+                let bytes: [u8; 32] = value.wrapping_abs().to_be_bytes();
+                let digits: Vec<Digits> = magnitude::from_be_bytes(&bytes);
+                return Some(from_unscaled(sign, digits, scale));
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::arrow::array::Array;
+    use std::str::FromStr;
+
+    fn numbers(values: &[&str]) -> Vec<BigNumber> {
+        return values
+            .iter()
+            .map(|x| BigNumber::from_str(x).unwrap())
+            .collect();
+    }
+
+    #[test]
+    fn test_decimal128_array() {
+        let values = numbers(&["1.5", "-19.99", "0", "12345678.9", "-0.01"]);
+        let array = BigNumber::to_decimal128_array(&values, 10, 2, OnOverflow::Error).unwrap();
+        assert_eq!(array.precision(), 10);
+        assert_eq!(array.scale(), 2);
+        assert_eq!(array.values().to_vec(), vec![150, -1999, 0, 1234567890, -1]);
+
+        let back: Vec<Option<BigNumber>> = BigNumber::from_decimal128_array(&array);
+        let strings: Vec<String> = back
+            .iter()
+            .map(|x| x.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(strings, ["1.50", "-19.99", "0.00", "12345678.90", "-0.01"]);
+
+        let values = numbers(&["15000", "-2000"]);
+        let array = BigNumber::to_decimal128_array(&values, 5, -3, OnOverflow::Error).unwrap();
+        assert_eq!(array.values().to_vec(), vec![15, -2]);
+        let back: Vec<Option<BigNumber>> = BigNumber::from_decimal128_array(&array);
+        assert_eq!(back[0].as_ref().unwrap().to_string(), "15000");
+
+        let values = numbers(&["99999999999999999999999999999999999999"]);
+        let array = BigNumber::to_decimal128_array(&values, 38, 0, OnOverflow::Error).unwrap();
+        assert_eq!(array.value(0), 10i128.pow(38) - 1);
+    }
+
+    #[test]
+    fn test_decimal256_array() {
+        let values = numbers(&[
+            "1.5",
+            "-19.99",
+            "0",
+            "-99999999999999999999999999999999999999999999999999999999999999999999999999.99",
+        ]);
+        let array = BigNumber::to_decimal256_array(&values, 76, 2, OnOverflow::Error).unwrap();
+        assert_eq!(array.value(0), i256::from_i128(150));
+        assert_eq!(array.value(1), i256::from_i128(-1999));
+        assert_eq!(
+            array.value(3),
+            i256::from_i128(10)
+                .wrapping_pow(76)
+                .wrapping_neg()
+                .wrapping_add(i256::ONE)
+        );
+
+        let back: Vec<Option<BigNumber>> = BigNumber::from_decimal256_array(&array);
+        let strings: Vec<String> = back
+            .iter()
+            .map(|x| x.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            strings,
+            [
+                "1.50",
+                "-19.99",
+                "0.00",
+                "-99999999999999999999999999999999999999999999999999999999999999999999999999.99"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decimal256_array_unchecked_values() {
+        // Arrow keeps values that do not fit the precision, down to i256::MIN.
+        let array = Decimal256Array::from(vec![i256::MIN, i256::MAX])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        let back: Vec<Option<BigNumber>> = BigNumber::from_decimal256_array(&array);
+        let max: BigNumber = BigNumber::from_integer(2).pow(255);
+        assert_eq!(back[0], Some(max.negated()));
+        assert_eq!(back[1], Some(max.difference(&BigNumber::one())));
+    }
+
+    #[test]
+    fn test_decimal_array_overflow() {
+        let values = numbers(&["1.5", "123456", "-2"]);
+        assert_eq!(
+            BigNumber::to_decimal128_array(&values, 5, 2, OnOverflow::Error),
+            Err(MathErrors::Overflow)
+        );
+        let array = BigNumber::to_decimal128_array(&values, 5, 2, OnOverflow::Null).unwrap();
+        assert_eq!(array.null_count(), 1);
+        assert!(array.is_null(1));
+        let back: Vec<Option<BigNumber>> = BigNumber::from_decimal128_array(&array);
+        assert!(back[1].is_none());
+        assert_eq!(back[2].as_ref().unwrap().to_string(), "-2.00");
+
+        let array = BigNumber::to_decimal256_array(&values, 5, 2, OnOverflow::Null).unwrap();
+        assert!(array.is_null(1));
+        let overflowed: Vec<usize> = (0..array.len()).filter(|i| array.is_null(*i)).collect();
+        assert_eq!(overflowed, [1]);
+
+        // Digits beyond the scale are an error in both modes.
+        let values = numbers(&["1.234"]);
+        assert_eq!(
+            BigNumber::to_decimal128_array(&values, 5, 2, OnOverflow::Null),
+            Err(MathErrors::PrecisionLoss)
+        );
+
+        // (precision, scale)
+        let test_data = [(0, 0), (39, 0), (5, 6), (10, 39)];
+        for (precision, scale) in test_data {
+            assert_eq!(
+                BigNumber::to_decimal128_array(&[], precision, scale, OnOverflow::Error),
+                Err(MathErrors::DomainError)
+            );
+        }
+        assert_eq!(
+            BigNumber::to_decimal256_array(&[], 77, 0, OnOverflow::Error),
+            Err(MathErrors::DomainError)
+        );
+    }
+}
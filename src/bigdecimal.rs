@@ -0,0 +1,105 @@
+#![warn(dead_code)]
+// External imports
+use ::bigdecimal::BigDecimal;
+
+// Inter crate imports
+use super::num_bigint::from_bigint;
+use super::num_bigint::to_bigint;
+use super::BigNumber;
+use super::MathErrors;
+
+impl From<&BigNumber> for BigDecimal {
+    /// Keeps every digit and the scale, so 1.50 stays 1.50.
+    fn from(input: &BigNumber) -> Self {
+        let (coefficient, scale) = input.coefficient();
+        return BigDecimal::new(to_bigint(input.sign, &coefficient), scale as i64);
+    }
+}
+
+impl From<BigNumber> for BigDecimal {
+    /// Keeps every digit and the scale, so 1.50 stays 1.50.
+    fn from(input: BigNumber) -> Self {
+        return BigDecimal::from(&input);
+    }
+}
+
+impl TryFrom<&BigDecimal> for BigNumber {
+    type Error = MathErrors;
+
+    /// A negative BigDecimal scale is applied as trailing zeros on the
+    /// integer portion. [MathErrors::Overflow] is returned if the scale is
+    /// beyond ±100,000, however many digits the BigDecimal has.
+    fn try_from(input: &BigDecimal) -> Result<Self, Self::Error> {
+        let (digits, exponent) = input.as_bigint_and_exponent();
+        let (sign, coefficient) = from_bigint(&digits);
+        return BigNumber::from_coefficient(sign, coefficient, 0)
+            .bounded_shift_decimal(-(exponent as i128));
+    }
+}
+
+impl TryFrom<BigDecimal> for BigNumber {
+    type Error = MathErrors;
+
+    /// See the conversion from &BigDecimal.
+    fn try_from(input: BigDecimal) -> Result<Self, Self::Error> {
+        return BigNumber::try_from(&input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::num_bigint::BigInt;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_bigdecimal_conversions() {
+        let test_data = [
+            "0",
+            "1.50",
+            "-0.000000000000000000000000000000000000000001",
+            "123456789012345678901234567890.123456789012345678901234567890",
+            "-1000",
+        ];
+        for x in test_data {
+            let number = BigNumber::from_str(x).unwrap();
+            let decimal: BigDecimal = BigDecimal::from(&number);
+            let expected = BigDecimal::from_str(x).unwrap();
+            assert_eq!(
+                decimal.as_bigint_and_exponent(),
+                expected.as_bigint_and_exponent()
+            );
+            let back: BigNumber = BigNumber::try_from(decimal).unwrap();
+            assert_eq!(back.to_string(), x);
+        }
+
+        // (digits, BigDecimal scale, expected)
+        let test_data = [
+            ("15", -3, "15000"),
+            ("-15", 3, "-0.015"),
+            ("0", -2, "0"),
+            ("7", 0, "7"),
+        ];
+        for (digits, scale, expected) in test_data {
+            let decimal = BigDecimal::new(BigInt::from_str(digits).unwrap(), scale);
+            let number = BigNumber::try_from(&decimal).unwrap();
+            assert_eq!(number.to_string(), expected);
+        }
+        let test_data = [i64::MIN, -i64::MAX, -100_001, i64::MAX, 100_001];
+        for scale in test_data {
+            let decimal = BigDecimal::new(BigInt::from(1), scale);
+            assert_eq!(BigNumber::try_from(&decimal), Err(MathErrors::Overflow));
+        }
+        let decimal = BigDecimal::new(BigInt::from(1), -100_000);
+        let number = BigNumber::try_from(&decimal).unwrap();
+        assert_eq!(number.integer_part().to_string().len(), 100_001);
+        let decimal = BigDecimal::new(BigInt::from(1), 100_000);
+        assert_eq!(BigNumber::try_from(&decimal).unwrap().scale(), 100_000);
+
+        // Only the scale is limited, not the digits.
+        let digits: String = "7".repeat(150_000);
+        let decimal = BigDecimal::new(BigInt::from_str(&digits).unwrap(), -3);
+        let number = BigNumber::try_from(&decimal).unwrap();
+        assert_eq!(number.to_string(), format!("{digits}000"));
+    }
+}
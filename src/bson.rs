@@ -0,0 +1,132 @@
+#![warn(dead_code)]
+// External imports
+use ::bson::Decimal128;
+
+// Inter crate imports
+use super::ieee754::try_to_decimal128_bid;
+use super::BigNumber;
+use super::MathErrors;
+
+impl TryFrom<&BigNumber> for Decimal128 {
+    type Error = MathErrors;
+
+    /// Values with more than 34 significant digits are rounded half to even.
+    /// So are values with digits below 10^-6176, the smallest exponent, which
+    /// can round to zero. The scale is kept where it fits, so 1.50 stays 1.50.
+    ///
+    /// [MathErrors::Overflow] is returned if the value is too large for a
+    /// Decimal128.
+    fn try_from(input: &BigNumber) -> Result<Self, Self::Error> {
+        let bits: u128 = try_to_decimal128_bid(input)?;
+        return Ok(Decimal128::from_bytes(bits.to_le_bytes()));
+    }
+}
+
+impl TryFrom<BigNumber> for Decimal128 {
+    type Error = MathErrors;
+
+    /// See the conversion from &BigNumber.
+    fn try_from(input: BigNumber) -> Result<Self, Self::Error> {
+        return Decimal128::try_from(&input);
+    }
+}
+
+impl TryFrom<&Decimal128> for BigNumber {
+    type Error = MathErrors;
+
+    /// Keeps every digit and the exponent. [MathErrors::DomainError] is
+    /// returned for NaN and the infinities.
+    fn try_from(input: &Decimal128) -> Result<Self, Self::Error> {
+        return BigNumber::from_decimal128_bid(u128::from_le_bytes(input.bytes()));
+    }
+}
+
+impl TryFrom<Decimal128> for BigNumber {
+    type Error = MathErrors;
+
+    /// See the conversion from &Decimal128.
+    fn try_from(input: Decimal128) -> Result<Self, Self::Error> {
+        return BigNumber::try_from(&input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_decimal128_conversions() {
+        // (BigNumber, Decimal128)
+        let test_data = [
+            ("0", "0"),
+            ("1.50", "1.50"),
+            ("-7.50", "-7.50"),
+            ("0.000001", "0.000001"),
+            (
+                "1234567890123456789012345678901234",
+                "1234567890123456789012345678901234",
+            ),
+            (
+                "123456789012345678901234567890.12345678",
+                "123456789012345678901234567890.1235",
+            ),
+            (
+                "12345678901234567890123456789012345",
+                "1.234567890123456789012345678901234E+34",
+            ),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = Decimal128::from_str(expected).unwrap();
+            let result: Decimal128 = Decimal128::try_from(&x).unwrap();
+            assert_eq!(result.bytes(), expected.bytes());
+        }
+
+        let test_data = ["0", "1.50", "-7.50", "-0.000001", "1E-6176", "1E+6144"];
+        for x in test_data {
+            let decimal = Decimal128::from_str(x).unwrap();
+            let number = BigNumber::try_from(decimal).unwrap();
+            let back = Decimal128::try_from(number).unwrap();
+            assert_eq!(back.bytes(), decimal.bytes());
+        }
+        let decimal = Decimal128::from_str("-1.50").unwrap();
+        assert_eq!(BigNumber::try_from(decimal).unwrap().to_string(), "-1.50");
+    }
+
+    #[test]
+    fn test_decimal128_errors() {
+        let max = BigNumber::from_str("9999999999999999999999999999999999")
+            .unwrap()
+            .shift_decimal(6111);
+        let expected = Decimal128::from_str("9.999999999999999999999999999999999E+6144").unwrap();
+        assert_eq!(
+            Decimal128::try_from(&max).unwrap().bytes(),
+            expected.bytes()
+        );
+        let x = BigNumber::from_str("1").unwrap().shift_decimal(6145);
+        assert_eq!(Decimal128::try_from(&x), Err(MathErrors::Overflow));
+        assert_eq!(Decimal128::try_from(x.negated()), Err(MathErrors::Overflow));
+
+        // Digits below the smallest exponent are rounded half to even.
+        // (coefficient, Decimal128)
+        let test_data = [
+            ("5", "0E-6176"),
+            ("6", "1E-6176"),
+            ("15", "2E-6176"),
+            ("25", "2E-6176"),
+            ("-25", "-2E-6176"),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap().shift_decimal(-6177);
+            let expected = Decimal128::from_str(expected).unwrap();
+            assert_eq!(Decimal128::try_from(&x).unwrap().bytes(), expected.bytes());
+        }
+
+        let test_data = ["NaN", "Infinity", "-Infinity"];
+        for x in test_data {
+            let decimal = Decimal128::from_str(x).unwrap();
+            assert_eq!(BigNumber::try_from(&decimal), Err(MathErrors::DomainError));
+        }
+    }
+}
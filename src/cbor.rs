@@ -0,0 +1,419 @@
+#![warn(dead_code)]
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+// The CBOR major types used here.
+const UNSIGNED: u8 = 0;
+const NEGATIVE: u8 = 1;
+const BYTES: u8 = 2;
+const ARRAY: u8 = 4;
+const TAG: u8 = 6;
+
+// The RFC 8949 tags used here.
+const POSITIVE_BIGNUM: u64 = 2;
+const NEGATIVE_BIGNUM: u64 = 3;
+const DECIMAL_FRACTION: u64 = 4;
+const BIGFLOAT: u64 = 5;
+
+// Ends an indefinite length item.
+const BREAK: u8 = 0xFF;
+
+// The largest bigfloat exponent, positive or negative, that is decoded.
+// m * 2^-k has k decimal places and working out 5^k takes time growing with
+// the square of k, so this keeps untrusted input cheap while still
+// covering every binary128 value, the smallest of which is 2^-16494.
+const MAX_BIGFLOAT_EXPONENT: u32 = 16494;
+
+// The longest bignum that is decoded, enough for any integer of 100,000
+// digits. Converting the bytes to digits takes time growing
+// with the square of their length.
+const MAX_BIGNUM_BYTES: usize = 41_525;
+
+/// Appends the head of a data item, with the argument in as few bytes as it
+/// needs.
+fn write_head(output: &mut Vec<u8>, major: u8, argument: u64) {
+    let major: u8 = major << 5;
+    if argument < 24 {
+        output.push(major | argument as u8);
+    } else if argument <= u8::MAX as u64 {
+        output.push(major | 24);
+        output.push(argument as u8);
+    } else if argument <= u16::MAX as u64 {
+        output.push(major | 25);
+        output.extend_from_slice(&(argument as u16).to_be_bytes());
+    } else if argument <= u32::MAX as u64 {
+        output.push(major | 26);
+        output.extend_from_slice(&(argument as u32).to_be_bytes());
+    } else {
+        output.push(major | 27);
+        output.extend_from_slice(&argument.to_be_bytes());
+    }
+}
+
+/// Appends an integer, using a bignum if it does not fit in a head.
+///
+/// Negative integers n are stored as -1 - n.
+fn write_integer(output: &mut Vec<u8>, sign: Sign, digits: &[Digits]) {
+    let (major, tag, digits) = if sign.is_negative() && !magnitude::is_zero(digits) {
+        (
+            NEGATIVE,
+            NEGATIVE_BIGNUM,
+            magnitude::subtract(digits, &[Digits::One]),
+        )
+    } else {
+        (
+            UNSIGNED,
+            POSITIVE_BIGNUM,
+            magnitude::significant(digits).to_vec(),
+        )
+    };
+    match magnitude::to_u128(&digits) {
+        Some(n) if n <= u64::MAX as u128 => write_head(output, major, n as u64),
+        _ => {
+            let bytes: Vec<u8> = magnitude::to_be_bytes(&digits);
+            write_head(output, TAG, tag);
+            write_head(output, BYTES, bytes.len() as u64);
+            output.extend_from_slice(&bytes);
+        }
+    }
+}
+
+/// Reads CBOR data items from the front of a slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(self: &mut Self, count: usize) -> Result<&'a [u8], MathErrors> {
+        if self.bytes.len() - self.position < count {
+            return Err(MathErrors::ParseError);
+        }
+        let result: &'a [u8] = &self.bytes[self.position..self.position + count];
+        self.position += count;
+        return Ok(result);
+    }
+
+    /// Reads the head of a data item, returning the major type and the
+    /// argument, or None for an indefinite length.
+    fn head(self: &mut Self) -> Result<(u8, Option<u64>), MathErrors> {
+        let initial: u8 = self.take(1)?[0];
+        let major: u8 = initial >> 5;
+        let size: usize = match initial & 0x1F {
+            additional @ 0..=23 => return Ok((major, Some(additional as u64))),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            31 => return Ok((major, None)),
+            _ => return Err(MathErrors::ParseError),
+        };
+        let argument: u64 = self
+            .take(size)?
+            .iter()
+            .fold(0, |total, byte| total << 8 | *byte as u64);
+        return Ok((major, Some(argument)));
+    }
+
+    /// Reads a byte string, joining the chunks of an indefinite length one.
+    fn byte_string(self: &mut Self) -> Result<Vec<u8>, MathErrors> {
+        match self.head()? {
+            (BYTES, Some(length)) => {
+                let length: usize = usize::try_from(length).or(Err(MathErrors::ParseError))?;
+                return Ok(self.take(length)?.to_vec());
+            }
+            (BYTES, None) => {
+                let mut result: Vec<u8> = Vec::new();
+                while self.bytes.get(self.position) != Some(&BREAK) {
+                    match self.head()? {
+                        (BYTES, Some(length)) => {
+                            let length: usize =
+                                usize::try_from(length).or(Err(MathErrors::ParseError))?;
+                            result.extend_from_slice(self.take(length)?);
+                        }
+                        _ => return Err(MathErrors::ParseError),
+                    }
+                }
+                self.position += 1;
+                return Ok(result);
+            }
+            _ => return Err(MathErrors::ParseError),
+        }
+    }
+
+    /// Reads an integer that fits in a head, as the exponent of a decimal
+    /// fraction or bigfloat has to.
+    fn small_integer(self: &mut Self) -> Result<i128, MathErrors> {
+        match self.head()? {
+            (UNSIGNED, Some(n)) => return Ok(n as i128),
+            (NEGATIVE, Some(n)) => return Ok(-1 - n as i128),
+            _ => return Err(MathErrors::ParseError),
+        }
+    }
+
+    /// Reads the byte string of a bignum as a magnitude.
+    ///
+    /// [MathErrors::Overflow] is returned if it is longer than
+    /// MAX_BIGNUM_BYTES.
+    fn bignum(self: &mut Self) -> Result<Vec<Digits>, MathErrors> {
+        let bytes: Vec<u8> = self.byte_string()?;
+        if bytes.len() > MAX_BIGNUM_BYTES {
+            return Err(MathErrors::Overflow);
+        }
+        return Ok(magnitude::from_be_bytes(&bytes));
+    }
+
+    /// Reads an integer or a bignum.
+    fn integer(self: &mut Self) -> Result<BigNumber, MathErrors> {
+        let start: usize = self.position;
+        match self.head()? {
+            (TAG, Some(POSITIVE_BIGNUM)) => {
+                let digits: Vec<Digits> = self.bignum()?;
+                return Ok(BigNumber::from_coefficient(Sign::Positive, digits, 0));
+            }
+            (TAG, Some(NEGATIVE_BIGNUM)) => {
+                let digits: Vec<Digits> = self.bignum()?;
+                let digits: Vec<Digits> = magnitude::add(&digits, &[Digits::One]);
+                return Ok(BigNumber::from_coefficient(Sign::Negative, digits, 0));
+            }
+            _ => {
+                self.position = start;
+                return Ok(BigNumber::from_integer(self.small_integer()?));
+            }
+        }
+    }
+
+    /// Reads a decimal fraction, a bigfloat, an integer or a bignum.
+    fn number(self: &mut Self) -> Result<BigNumber, MathErrors> {
+        let start: usize = self.position;
+        let tag: u64 = match self.head()? {
+            (TAG, Some(tag)) if tag == DECIMAL_FRACTION || tag == BIGFLOAT => tag,
+            _ => {
+                self.position = start;
+                return self.integer();
+            }
+        };
+        if self.head()? != (ARRAY, Some(2)) {
+            return Err(MathErrors::ParseError);
+        }
+        let exponent: i128 = self.small_integer()?;
+        let mantissa: BigNumber = self.integer()?;
+        if tag == DECIMAL_FRACTION {
+            return mantissa.bounded_shift_decimal(exponent);
+        }
+        // m * 2^-k is m * 5^k / 10^k, so every bigfloat is an exact decimal.
+        let power: u32 = match u32::try_from(exponent.unsigned_abs()) {
+            Ok(power) if power <= MAX_BIGFLOAT_EXPONENT => power,
+            _ => return Err(MathErrors::Overflow),
+        };
+        if exponent >= 0 {
+            return Ok(mantissa.product(&BigNumber::from_integer(2).pow(power)));
+        }
+        let mut result: BigNumber = mantissa
+            .product(&BigNumber::from_integer(5).pow(power))
+            .shift_decimal(-(power as isize));
+        result.normalize();
+        return Ok(result);
+    }
+}
+
+impl BigNumber {
+    /// Encodes self as an RFC 8949 decimal fraction, tag 4 holding the array
+    /// [exponent, mantissa].
+    ///
+    /// The exponent is the negated scale, so 1.50 is stored as [-2, 150].
+    /// Mantissas that do not fit in 64 bits are stored as bignums, tag 2 or
+    /// tag 3 holding the big endian bytes.
+    pub fn to_cbor(self: &Self) -> Vec<u8> {
+        let (coefficient, scale) = self.coefficient();
+        let mut result: Vec<u8> = Vec::new();
+        write_head(&mut result, TAG, DECIMAL_FRACTION);
+        write_head(&mut result, ARRAY, 2);
+        write_integer(
+            &mut result,
+            Sign::Negative,
+            &magnitude::from_u128(scale as u128),
+        );
+        write_integer(&mut result, self.sign, &coefficient);
+        return result;
+    }
+
+    /// Decodes a CBOR decimal fraction, bigfloat, integer or bignum, see
+    /// [BigNumber::to_cbor].
+    ///
+    /// Decimal fractions keep their scale. A bigfloat m * 2^e always has an
+    /// exact decimal value, which is returned without trailing zeros.
+    ///
+    /// [MathErrors::ParseError] is returned if the bytes are not exactly one
+    /// of those items. As the bytes may come from anywhere,
+    /// [MathErrors::Overflow] is returned for a decimal fraction exponent
+    /// beyond ±100,000, for a bigfloat exponent beyond ±16494 and for a
+    /// bignum longer than 41,525 bytes.
+    pub fn from_cbor(bytes: &[u8]) -> Result<BigNumber, MathErrors> {
+        let mut reader: Reader = Reader { bytes, position: 0 };
+        let result: BigNumber = reader.number()?;
+        if reader.position != bytes.len() {
+            return Err(MathErrors::ParseError);
+        }
+        return Ok(result);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_cbor() {
+        // (value, bytes)
+        let test_data: [(&str, &[u8]); 10] = [
+            // The decimal fraction example from RFC 8949.
+            ("273.15", &[0xC4, 0x82, 0x21, 0x19, 0x6A, 0xB3]),
+            ("0", &[0xC4, 0x82, 0x00, 0x00]),
+            ("1.50", &[0xC4, 0x82, 0x21, 0x18, 0x96]),
+            ("-1.5", &[0xC4, 0x82, 0x20, 0x2E]),
+            (
+                "-0.0000000000000000000000001",
+                &[0xC4, 0x82, 0x38, 0x18, 0x20],
+            ),
+            (
+                "18446744073709551615",
+                &[
+                    0xC4, 0x82, 0x00, 0x1B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+                ],
+            ),
+            (
+                "18446744073709551616",
+                &[
+                    0xC4, 0x82, 0x00, 0xC2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00,
+                ],
+            ),
+            (
+                "-18446744073709551616",
+                &[
+                    0xC4, 0x82, 0x00, 0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+                ],
+            ),
+            (
+                "-18446744073709551617",
+                &[
+                    0xC4, 0x82, 0x00, 0xC3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00,
+                ],
+            ),
+            (
+                "-1844674407370955161.7",
+                &[
+                    0xC4, 0x82, 0x20, 0xC3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00,
+                ],
+            ),
+        ];
+        for (value, bytes) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_cbor(), bytes);
+            assert_eq!(BigNumber::from_cbor(bytes).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn test_cbor_decoding() {
+        // (bytes, value)
+        let test_data: [(&[u8], &str); 9] = [
+            // The bigfloat example from RFC 8949.
+            (&[0xC5, 0x82, 0x20, 0x03], "1.5"),
+            (&[0xC5, 0x82, 0x21, 0x06], "1.5"),
+            (&[0xC5, 0x82, 0x03, 0x24], "-40"),
+            (
+                &[0xC5, 0x82, 0x38, 0x1E, 0x01],
+                "0.0000000004656612873077392578125",
+            ),
+            (&[0xC5, 0x82, 0x00, 0x00], "0"),
+            (&[0xC4, 0x82, 0x02, 0x0F], "1500"),
+            // Tag and array heads with longer arguments than they need.
+            (&[0xD8, 0x04, 0x98, 0x02, 0x21, 0x19, 0x6A, 0xB3], "273.15"),
+            (&[0x18, 0x64], "100"),
+            // A bignum with its bytes in two chunks.
+            (&[0xC2, 0x5F, 0x41, 0x01, 0x42, 0x00, 0x00, 0xFF], "65536"),
+        ];
+        for (bytes, value) in test_data {
+            assert_eq!(BigNumber::from_cbor(bytes).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn test_cbor_errors() {
+        // (bytes, error)
+        let test_data: [(&[u8], MathErrors); 11] = [
+            (&[], MathErrors::ParseError),
+            (&[0xC4, 0x82, 0x00, 0x00, 0x00], MathErrors::ParseError),
+            (&[0xC4, 0x83, 0x00, 0x00, 0x00], MathErrors::ParseError),
+            (&[0xC4, 0x82, 0x21, 0x19, 0x6A], MathErrors::ParseError),
+            (
+                &[0xC4, 0x82, 0xC2, 0x41, 0x01, 0x00],
+                MathErrors::ParseError,
+            ),
+            (
+                &[0xC4, 0x82, 0x00, 0xF9, 0x3C, 0x00],
+                MathErrors::ParseError,
+            ),
+            (&[0xC6, 0x82, 0x00, 0x00], MathErrors::ParseError),
+            (&[0x1C], MathErrors::ParseError),
+            (
+                &[0xC2, 0x5F, 0x41, 0x01, 0x01, 0xFF],
+                MathErrors::ParseError,
+            ),
+            (
+                &[
+                    0xC5, 0x82, 0x1B, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+                ],
+                MathErrors::Overflow,
+            ),
+            (
+                &[
+                    0xC4, 0x82, 0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+                ],
+                MathErrors::Overflow,
+            ),
+        ];
+        for (bytes, error) in test_data {
+            assert_eq!(BigNumber::from_cbor(bytes), Err(error));
+        }
+
+        // Exponents that fit the types but would take too much memory or time.
+        let test_data: [&[u8]; 4] = [
+            &[
+                0xC4, 0x82, 0x1B, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01,
+            ],
+            &[0xC4, 0x82, 0x1A, 0x00, 0x01, 0x86, 0xA1, 0x01],
+            &[0xC5, 0x82, 0x3A, 0xFF, 0xFF, 0xFF, 0xFE, 0x01],
+            &[0xC5, 0x82, 0x39, 0x40, 0x6E, 0x01],
+        ];
+        for bytes in test_data {
+            assert_eq!(BigNumber::from_cbor(bytes), Err(MathErrors::Overflow));
+        }
+        // The largest exponents allowed still decode.
+        let x = BigNumber::from_cbor(&[0xC4, 0x82, 0x1A, 0x00, 0x01, 0x86, 0xA0, 0x01]).unwrap();
+        assert_eq!(x.integer_part().to_string().len(), 100_001);
+        let x = BigNumber::from_cbor(&[0xC5, 0x82, 0x39, 0x40, 0x6D, 0x01]).unwrap();
+        assert_eq!(x.scale(), 16494);
+
+        // So are bignums too long to convert quickly.
+        let bignum = |length: usize| -> Vec<u8> {
+            let mut result: Vec<u8> = vec![0xC2, 0x5A];
+            result.extend_from_slice(&(length as u32).to_be_bytes());
+            result.resize(result.len() + length, 0xFF);
+            return result;
+        };
+        let bytes: Vec<u8> = bignum(MAX_BIGNUM_BYTES + 1);
+        assert_eq!(BigNumber::from_cbor(&bytes), Err(MathErrors::Overflow));
+        let x = BigNumber::from_cbor(&bignum(MAX_BIGNUM_BYTES)).unwrap();
+        assert_eq!(x.integer_part().to_string().len(), 100_003);
+    }
+}
@@ -0,0 +1,240 @@
+#![warn(dead_code)]
+// External imports
+use lazy_static::lazy_static;
+use std::cmp;
+use std::sync::Mutex;
+
+// Inter crate imports
+use super::exponential::GUARD_DIGITS;
+use super::BigNumber;
+
+// Each cache holds the longest expansion computed so far along with the
+// number of significant digits it is correct to.
+lazy_static! {
+    static ref PI: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+    static ref E: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+    static ref LN2: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+    static ref LN10: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+    static ref SQRT2: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+    static ref PHI: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+}
+
+/// Rounds the cached expansion to digits significant digits, first
+/// replacing it using compute if it is too short.
+fn cached(
+    cache: &Mutex<(usize, BigNumber)>,
+    digits: usize,
+    compute: fn(usize) -> BigNumber,
+) -> BigNumber {
+    let digits: usize = cmp::max(digits, 1);
+    // The expansion is only replaced once compute returns, so a panic while
+    // the lock is held leaves a usable value behind.
+    let mut expansion = cache.lock().unwrap_or_else(|error| error.into_inner());
+    if expansion.0 < digits + GUARD_DIGITS {
+        let length: usize = cmp::max(digits + GUARD_DIGITS, 2 * expansion.0);
+        *expansion = (length, compute(length));
+    }
+    return expansion.1.round_to_precision(digits);
+}
+
+impl BigNumber {
+    /// Returns π rounded to digits significant digits.
+    pub fn pi(digits: usize) -> BigNumber {
+        return cached(&PI, digits, BigNumber::chudnovsky);
+    }
+
+    /// Returns e rounded to digits significant digits.
+    pub fn e(digits: usize) -> BigNumber {
+        return cached(&E, digits, BigNumber::euler);
+    }
+
+    /// Returns the natural logarithm of 2 rounded to digits significant
+    /// digits.
+    pub fn ln2(digits: usize) -> BigNumber {
+        return cached(&LN2, digits, BigNumber::ln2_series);
+    }
+
+    /// Returns the natural logarithm of 10 rounded to digits significant
+    /// digits.
+    pub fn ln10(digits: usize) -> BigNumber {
+        return cached(&LN10, digits, BigNumber::ln10_series);
+    }
+
+    /// Returns the square root of 2 rounded to digits significant digits.
+    pub fn sqrt2(digits: usize) -> BigNumber {
+        return cached(&SQRT2, digits, |digits| {
+            return BigNumber::from_integer(2).sqrt(digits).unwrap();
+        });
+    }
+
+    /// Returns the golden ratio rounded to digits significant digits.
+    pub fn phi(digits: usize) -> BigNumber {
+        return cached(&PHI, digits, |digits| {
+            // φ = (1 + √5) / 2
+            let root5: BigNumber = BigNumber::from_integer(5).sqrt(digits + 2).unwrap();
+            return root5
+                .sum(&BigNumber::one())
+                .product(&BigNumber::half())
+                .round_to_precision(digits);
+        });
+    }
+
+    /// Computes π to digits significant digits with the Chudnovsky series.
+    fn chudnovsky(digits: usize) -> BigNumber {
+        let working: usize = digits + GUARD_DIGITS;
+        // Every term of the series adds a little over 14 digits.
+        let terms: i128 = (working / 14 + 2) as i128;
+        let (_, q, t) = BigNumber::chudnovsky_split(0, terms);
+        // π = 426880 √10005 Q / T
+        let root: BigNumber = BigNumber::from_integer(10005).sqrt(working).unwrap();
+        return BigNumber::from_integer(426880)
+            .product(&root)
+            .product(&q)
+            .quotient_to_precision(&t, digits)
+            .unwrap();
+    }
+
+    /// Binary splitting of the Chudnovsky series over the terms [a, b).
+    ///
+    /// Returns the P, Q and T products described by Haible and Papanikolaou.
+    fn chudnovsky_split(a: i128, b: i128) -> (BigNumber, BigNumber, BigNumber) {
+        if b - a == 1 {
+            if a == 0 {
+                return (
+                    BigNumber::one(),
+                    BigNumber::one(),
+                    BigNumber::from_integer(13591409),
+                );
+            }
+            // 640320^3 / 24
+            let c3_over_24: i128 = 10939058860032000;
+            let p: BigNumber = BigNumber::from_integer((6 * a - 5) * (2 * a - 1) * (6 * a - 1));
+            let q: BigNumber =
+                BigNumber::from_integer(a * a * a).product(&BigNumber::from_integer(c3_over_24));
+            let mut t: BigNumber = p.product(&BigNumber::from_integer(13591409 + 545140134 * a));
+            if a % 2 == 1 {
+                t.negate();
+            }
+            return (p, q, t);
+        }
+        let m: i128 = (a + b) / 2;
+        let (p_am, q_am, t_am) = BigNumber::chudnovsky_split(a, m);
+        let (p_mb, q_mb, t_mb) = BigNumber::chudnovsky_split(m, b);
+        return (
+            p_am.product(&p_mb),
+            q_am.product(&q_mb),
+            q_mb.product(&t_am).sum(&p_am.product(&t_mb)),
+        );
+    }
+
+    /// Computes e to digits significant digits by binary splitting the sum of
+    /// 1/k!.
+    fn euler(digits: usize) -> BigNumber {
+        let limit: usize = digits + GUARD_DIGITS;
+        // Sum terms until log10(n!) exceeds the number of digits needed.
+        let mut terms: i128 = 1;
+        let mut log_factorial: f64 = 0.0;
+        while log_factorial < limit as f64 {
+            terms += 1;
+            log_factorial += (terms as f64).log10();
+        }
+        let (p, q) = BigNumber::euler_split(0, terms);
+        // e = 1 + P / Q
+        return BigNumber::one()
+            .sum(&p.quotient_to_precision(&q, limit).unwrap())
+            .round_to_precision(digits);
+    }
+
+    /// Binary splitting of the sum of 1 / ((a + 1)(a + 2)...k) for k in
+    /// (a, b].
+    fn euler_split(a: i128, b: i128) -> (BigNumber, BigNumber) {
+        if b - a == 1 {
+            return (BigNumber::one(), BigNumber::from_integer(b));
+        }
+        let m: i128 = (a + b) / 2;
+        let (p_am, q_am) = BigNumber::euler_split(a, m);
+        let (p_mb, q_mb) = BigNumber::euler_split(m, b);
+        return (p_am.product(&q_mb).sum(&p_mb), q_am.product(&q_mb));
+    }
+
+    /// Computes the natural logarithm of two to digits significant digits.
+    fn ln2_series(digits: usize) -> BigNumber {
+        // ln(2) = 2 atanh(1/3)
+        let scale: usize = digits + 2;
+        let third: BigNumber = BigNumber::one()
+            .quotient(&BigNumber::from_integer(3), scale)
+            .unwrap();
+        return third
+            .atanh_series(scale)
+            .product(&BigNumber::from_integer(2))
+            .round_to_precision(digits);
+    }
+
+    /// Computes the natural logarithm of ten to digits significant digits.
+    fn ln10_series(digits: usize) -> BigNumber {
+        // ln(10) = 3 ln(2) + ln(1.25) = 3 ln(2) + 2 atanh(1/9)
+        let scale: usize = digits + 2;
+        let ninth: BigNumber = BigNumber::one()
+            .quotient(&BigNumber::from_integer(9), scale)
+            .unwrap();
+        return BigNumber::ln2(scale)
+            .product(&BigNumber::from_integer(3))
+            .sum(
+                &ninth
+                    .atanh_series(scale)
+                    .product(&BigNumber::from_integer(2)),
+            )
+            .round_to_precision(digits);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_cached_after_panic() {
+        let cache: Mutex<(usize, BigNumber)> = Mutex::new((0, BigNumber::zero()));
+        let result = std::panic::catch_unwind(|| {
+            cached(&cache, 5, |_| panic!("compute failed"));
+        });
+        assert!(result.is_err());
+        assert!(cache.is_poisoned());
+        let value: BigNumber = cached(&cache, 5, |digits| {
+            return BigNumber::from_integer(2).sqrt(digits).unwrap();
+        });
+        assert_eq!(value, BigNumber::from_str("1.4142").unwrap());
+    }
+
+    #[test]
+    fn test_constants() {
+        let test_data = [
+            (
+                BigNumber::pi(50),
+                "3.1415926535897932384626433832795028841971693993751",
+            ),
+            (BigNumber::pi(1), "3"),
+            (
+                BigNumber::e(50),
+                "2.7182818284590452353602874713526624977572470937000",
+            ),
+            (BigNumber::ln2(30), "0.693147180559945309417232121458"),
+            (BigNumber::ln10(30), "2.30258509299404568401799145468"),
+            (BigNumber::sqrt2(30), "1.41421356237309504880168872421"),
+            (BigNumber::phi(30), "1.61803398874989484820458683437"),
+            (BigNumber::pi(9), "3.14159265"),
+        ];
+        for (constant, expected) in test_data {
+            assert_eq!(constant, BigNumber::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_pi_digits() {
+        // The 762nd through 767th decimal places of π are the Feynman point.
+        let pi: String = BigNumber::pi(800).to_string();
+        assert_eq!(&pi[763..769], "999999");
+        assert_eq!(BigNumber::pi(5), BigNumber::from_str("3.1416").unwrap());
+    }
+}
@@ -5,6 +5,7 @@ use std::str::FromStr;
 // Internal imports
 use super::MathErrors;
 
+/// A single decimal digit.
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub enum Digits {
     #[default]
@@ -26,32 +27,86 @@ impl Default for &Digits {
     }
 }
 
-impl From<char> for Digits {
-    fn from(input: char) -> Self {
+impl TryFrom<char> for Digits {
+    type Error = MathErrors;
+
+    /// Converts the characters '0' through '9'.
+    ///
+    /// [MathErrors::ParseError] is returned for any other character, including
+    /// non-ASCII Unicode digits, matching [Digits::checked_from_ascii] and
+    /// [BigNumber::from_str](crate::BigNumber).
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match u8::try_from(input)
+            .ok()
+            .and_then(Digits::checked_from_ascii)
+        {
+            Some(digit) => return Ok(digit),
+            None => return Err(MathErrors::ParseError),
+        }
+    }
+}
+
+impl TryFrom<u8> for Digits {
+    type Error = MathErrors;
+
+    /// Converts the values 0 through 9. Use [Digits::checked_from_ascii] for
+    /// the characters b'0' through b'9'.
+    ///
+    /// [MathErrors::InvalidDigit] is returned for any larger value.
+    fn try_from(input: u8) -> Result<Self, Self::Error> {
+        return Digits::try_from(input as u128);
+    }
+}
+
+impl TryFrom<u128> for Digits {
+    type Error = MathErrors;
+
+    /// Converts the values 0 through 9.
+    ///
+    /// [MathErrors::InvalidDigit] is returned for any larger value.
+    fn try_from(input: u128) -> Result<Self, Self::Error> {
         match input {
-            '0' => Digits::Zero,
-            '1' => Digits::One,
-            '2' => Digits::Two,
-            '3' => Digits::Three,
-            '4' => Digits::Four,
-            '5' => Digits::Five,
-            '6' => Digits::Six,
-            '7' => Digits::Seven,
-            '8' => Digits::Eight,
-            '9' => Digits::Nine,
-            _ => Digits::Zero,
+            0 => return Ok(Digits::Zero),
+            1 => return Ok(Digits::One),
+            2 => return Ok(Digits::Two),
+            3 => return Ok(Digits::Three),
+            4 => return Ok(Digits::Four),
+            5 => return Ok(Digits::Five),
+            6 => return Ok(Digits::Six),
+            7 => return Ok(Digits::Seven),
+            8 => return Ok(Digits::Eight),
+            9 => return Ok(Digits::Nine),
+            _ => return Err(MathErrors::InvalidDigit),
         }
     }
 }
 
 impl Digits {
+    /// Converts an ASCII byte b'0' through b'9', returning None for any
+    /// other byte.
+    pub const fn checked_from_ascii(input: u8) -> Option<Digits> {
+        match input {
+            b'0' => return Some(Digits::Zero),
+            b'1' => return Some(Digits::One),
+            b'2' => return Some(Digits::Two),
+            b'3' => return Some(Digits::Three),
+            b'4' => return Some(Digits::Four),
+            b'5' => return Some(Digits::Five),
+            b'6' => return Some(Digits::Six),
+            b'7' => return Some(Digits::Seven),
+            b'8' => return Some(Digits::Eight),
+            b'9' => return Some(Digits::Nine),
+            _ => return None,
+        }
+    }
+
     /// Adds rhs to lhs with any carry over ammount returned.
-    pub const fn addition(self: &Self, rhs: Digits) -> (Digits, Digits) {
+    pub(crate) const fn addition(self: &Self, rhs: Digits) -> (Digits, Digits) {
         return ADDITION_MATRIX[self.to_usize()][rhs.to_usize()];
     }
 
     /// Adds rhs and carry to self with any borrowed amount returned.
-    pub const fn fused_addition(&self, rhs: Digits, carry: Digits) -> (Digits, Digits) {
+    pub(crate) const fn fused_addition(&self, rhs: Digits, carry: Digits) -> (Digits, Digits) {
         let (y, c) = rhs.addition(carry);
         let (x, c2) = self.addition(y);
         let (y, _) = c.addition(c2);
@@ -61,7 +116,8 @@ impl Digits {
     /// Returns the complement of a Digit.
     ///
     /// Used to speed up subtraction.
-    pub const fn complement(x: Digits) -> Digits {
+    #[allow(dead_code)]
+    pub(crate) const fn complement(x: Digits) -> Digits {
         match x {
             Digits::Zero => return Digits::Nine,
             Digits::One => return Digits::Eight,
@@ -77,11 +133,11 @@ impl Digits {
     }
 
     /// Subtracts rhs from self with any borrowed ammount returned.
-    pub const fn subtract(&self, rhs: Digits) -> (Digits, Digits) {
+    pub(crate) const fn subtract(&self, rhs: Digits) -> (Digits, Digits) {
         return SUBTRACTION_MATRIX[self.to_usize()][rhs.to_usize()];
     }
 
-    pub const fn fused_subtraction(&self, rhs: Digits, carry: Digits) -> (Digits, Digits) {
+    pub(crate) const fn fused_subtraction(&self, rhs: Digits, carry: Digits) -> (Digits, Digits) {
         let (y, c) = self.subtract(rhs);
         let (x, c2) = y.subtract(carry);
         let (y, _) = c.addition(c2);
@@ -89,14 +145,16 @@ impl Digits {
     }
 
     /// Multiplies self by rhs with any carry over amount returned.
-    pub const fn multiply(&self, rhs: Digits) -> (Digits, Digits) {
+    #[allow(dead_code)]
+    pub(crate) const fn multiply(&self, rhs: Digits) -> (Digits, Digits) {
         return MULTIPLICATION_MATRIX[self.to_usize()][rhs.to_usize()];
     }
 
     /// Divides self by rhs with any remainder returned.
     ///
     /// [MathErrors::DivisionByZero] is returned if self or rhs are 0.
-    pub const fn divide(&self, rhs: Digits) -> Result<(Digits, Digits), MathErrors> {
+    #[allow(dead_code)]
+    pub(crate) const fn divide(&self, rhs: Digits) -> Result<(Digits, Digits), MathErrors> {
         return DIVISION_MATRIX[self.to_usize()][rhs.to_usize()];
     }
 
@@ -151,39 +209,6 @@ impl Digits {
     }
 }
 
-impl From<u128> for Digits {
-    fn from(input: u128) -> Self {
-        if input == 0 {
-            return Digits::Zero;
-        }
-        if input == 1 {
-            return Digits::One;
-        }
-        if input == 2 {
-            return Digits::Two;
-        }
-        if input == 3 {
-            return Digits::Three;
-        }
-        if input == 4 {
-            return Digits::Four;
-        }
-        if input == 5 {
-            return Digits::Five;
-        }
-        if input == 6 {
-            return Digits::Six;
-        }
-        if input == 7 {
-            return Digits::Seven;
-        }
-        if input == 8 {
-            return Digits::Eight;
-        }
-        return Digits::Nine;
-    }
-}
-
 const ADDITION_MATRIX: [[(Digits, Digits); 10]; 10] = [
     [
         (Digits::Zero, Digits::Zero),
@@ -430,6 +455,7 @@ const SUBTRACTION_MATRIX: [[(Digits, Digits); 10]; 10] = [
     ],
 ];
 
+#[allow(dead_code)]
 const MULTIPLICATION_MATRIX: [[(Digits, Digits); 10]; 10] = [
     [
         (Digits::Zero, Digits::Zero),
@@ -553,6 +579,7 @@ const MULTIPLICATION_MATRIX: [[(Digits, Digits); 10]; 10] = [
     ],
 ];
 
+#[allow(dead_code)]
 const DIVISION_MATRIX: [[Result<(Digits, Digits), MathErrors>; 10]; 10] = [
     [
         Err(MathErrors::DivisionByZero),
@@ -764,4 +791,33 @@ mod tests {
             assert_eq!(left.divide(right), expected);
         }
     }
+
+    #[test]
+    fn test_digit_conversions() {
+        let test_data = [
+            ('0', 0, b'0', Some(Digits::Zero)),
+            ('7', 7, b'7', Some(Digits::Seven)),
+            ('9', 9, b'9', Some(Digits::Nine)),
+            ('a', 10, b'a', None),
+            ('-', 255, b'-', None),
+            ('\u{0663}', 48, b' ', None),
+            ('\u{ff17}', 17, 0xff, None),
+        ];
+        for (character, value, byte, expected) in test_data {
+            assert_eq!(
+                Digits::try_from(character),
+                expected.ok_or(MathErrors::ParseError)
+            );
+            assert_eq!(
+                Digits::try_from(value as u8),
+                expected.ok_or(MathErrors::InvalidDigit)
+            );
+            assert_eq!(
+                Digits::try_from(value as u128),
+                expected.ok_or(MathErrors::InvalidDigit)
+            );
+            assert_eq!(Digits::checked_from_ascii(byte), expected);
+        }
+        assert_eq!(Digits::try_from(u128::MAX), Err(MathErrors::InvalidDigit));
+    }
 }
@@ -0,0 +1,331 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::exponential::GUARD_DIGITS;
+use super::BigNumber;
+use super::MathErrors;
+
+// Halley's method needs a handful of steps from the starting guess, so this
+// is only reached if the stopping test never passes.
+const MAX_HALLEY_ITERATIONS: usize = 50;
+
+impl BigNumber {
+    /// Returns the error function of self rounded to precision significant
+    /// digits.
+    pub fn erf(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        if self.is_zero() {
+            return BigNumber::zero();
+        }
+        let x: BigNumber = self.abs();
+        let working: usize = precision + GUARD_DIGITS;
+        // erf(x) = 1 - erfc(x) and erfc(x) < e^(-x^2), so once x^2 passes
+        // working ln(10) the result rounds to one.
+        let mut result: BigNumber = if x.square_exceeds(working) {
+            BigNumber::one()
+        } else {
+            x.erf_series(working)
+        };
+        if self.is_negative() {
+            result.negate();
+        }
+        return result.round_to_precision(precision);
+    }
+
+    /// Returns the complementary error function, 1 - erf(self), rounded to
+    /// precision significant digits.
+    ///
+    /// The result keeps its relative precision far into the tail where it is
+    /// much smaller than any f64.
+    pub fn erfc(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        let working: usize = precision + GUARD_DIGITS;
+        if self.is_zero() {
+            return BigNumber::one();
+        }
+        if self.is_negative() {
+            // erfc(-x) = 1 + erf(x) has no cancellation.
+            return BigNumber::one()
+                .sum(&self.abs().erf(working))
+                .round_to_precision(precision);
+        }
+        if self.square_exceeds(working) {
+            return self
+                .erfc_continued_fraction(working)
+                .round_to_precision(precision);
+        }
+        // 1 - erf(x) cancels as many leading digits as erfc(x) has leading
+        // zeros, which a first estimate shows. Past the continued fraction
+        // limit above there are never more than working of them.
+        let estimate: BigNumber = BigNumber::one().difference(&self.erf_series(working));
+        let lost: usize = if estimate.is_zero() {
+            working
+        } else {
+            estimate.adjusted_exponent().unsigned_abs()
+        };
+        return BigNumber::one()
+            .difference(&self.erf_series(working + lost))
+            .round_to_precision(precision);
+    }
+
+    /// Returns the standard normal cumulative distribution function of self
+    /// rounded to precision significant digits.
+    pub fn norm_cdf(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        // Φ(x) = erfc(-x / √2) / 2. Below zero that is erfc of a positive
+        // argument, worked out without cancellation, and above zero it is
+        // (1 + erf(x / √2)) / 2, so the upper tail never subtracts from one.
+        // The relative error of erfc(u) is about 2u^2 times the relative
+        // error of u, which costs as many digits as x^2 has.
+        let square: BigNumber = self.product(self);
+        let working: usize =
+            precision + GUARD_DIGITS + cmp::max(0, square.adjusted_exponent() + 1) as usize;
+        let root2: BigNumber = BigNumber::sqrt2(working);
+        let argument: BigNumber = self
+            .negated()
+            .quotient_to_precision(&root2, working)
+            .unwrap();
+        return argument
+            .erfc(working)
+            .product(&BigNumber::half())
+            .round_to_precision(precision);
+    }
+
+    /// Returns the quantile function of the standard normal distribution, the
+    /// inverse of [BigNumber::norm_cdf], rounded to precision significant
+    /// digits.
+    ///
+    /// [MathErrors::DomainError] is returned unless self is strictly between
+    /// 0 and 1.
+    pub fn norm_ppf(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        let precision: usize = cmp::max(precision, 1);
+        let one: BigNumber = BigNumber::one();
+        if self.is_negative() || self.is_zero() || *self >= one {
+            return Err(MathErrors::DomainError);
+        }
+        let half: BigNumber = BigNumber::half();
+        match self.cmp(&half) {
+            Ordering::Equal => return Ok(BigNumber::zero()),
+            // The quantile is antisymmetric about one half and 1 - p is exact.
+            Ordering::Greater => {
+                return Ok(one.difference(self).norm_ppf(precision)?.negated());
+            }
+            Ordering::Less => {}
+        }
+        let working: usize = precision + GUARD_DIGITS;
+
+        // Start from Abramowitz and Stegun 26.2.23 which is good to about
+        // four digits. ln(p) fits in an f64 even when p does not.
+        let ln_p: f64 = self.ln(20)?.to_string().parse().unwrap();
+        let t: f64 = (-2.0 * ln_p).sqrt();
+        let guess: f64 = -(t
+            - (2.515517 + 0.802853 * t + 0.010328 * t * t)
+                / (1.0 + 1.432788 * t + 0.189269 * t * t + 0.001308 * t * t * t));
+        let mut x: BigNumber = format!("{guess:.6}").parse().unwrap();
+
+        // Halley's method on Φ(x) - p roughly triples the correct digits at
+        // every step.
+        let root_two_pi: BigNumber = BigNumber::pi(working)
+            .product(&BigNumber::from_integer(2))
+            .sqrt(working)
+            .unwrap();
+        // Near one half Φ(x) only resolves x to about 10^-working, so the
+        // stopping test treats x as never smaller than that.
+        let floor: isize = -(working as isize);
+        for _ in 0..MAX_HALLEY_ITERATIONS {
+            let density: BigNumber = x
+                .product(&x)
+                .product(&half)
+                .negated()
+                .exp(working)
+                .quotient_to_precision(&root_two_pi, working)
+                .unwrap();
+            let error: BigNumber = x.norm_cdf(working).difference(self);
+            if error.is_zero() {
+                break;
+            }
+            let t: BigNumber = error.quotient_to_precision(&density, working).unwrap();
+            let correction: BigNumber = t
+                .quotient_to_precision(&one.sum(&x.product(&t).product(&half)), working)
+                .unwrap();
+            x = x.difference(&correction).round_to_precision(working);
+            let size: isize = cmp::max(x.adjusted_exponent(), floor);
+            if correction.adjusted_exponent() < size - working as isize + 2 {
+                break;
+            }
+        }
+        return Ok(x.round_to_precision(precision));
+    }
+
+    /// Returns erf(self) for non-negative self to precision significant
+    /// digits.
+    ///
+    /// Uses erf(x) = 2/√π e^(-x^2) Σ 2^n x^(2n+1) / (1 3 5 ... (2n+1)) whose
+    /// terms are all positive, so nothing cancels.
+    fn erf_series(self: &Self, precision: usize) -> BigNumber {
+        let working: usize = precision + GUARD_DIGITS;
+        let two_square: BigNumber = self.product(self).product(&BigNumber::from_integer(2));
+        let mut term: BigNumber = self.clone();
+        let mut sum: BigNumber = BigNumber::zero();
+        let mut n: i128 = 0;
+        loop {
+            sum = sum.sum(&term).round_to_precision(working);
+            n += 1;
+            term = term
+                .product(&two_square)
+                .quotient_to_precision(&BigNumber::from_integer(2 * n + 1), working)
+                .unwrap();
+            // The terms grow while 2x^2 > 2n + 1 and only then shrink.
+            let shrinking: bool = two_square < BigNumber::from_integer(2 * n + 1);
+            if term.is_zero()
+                || (shrinking
+                    && term.adjusted_exponent() < sum.adjusted_exponent() - working as isize)
+            {
+                break;
+            }
+        }
+        let root_pi: BigNumber = BigNumber::pi(working).sqrt(working).unwrap();
+        return self
+            .product(self)
+            .negated()
+            .exp(working)
+            .product(&sum)
+            .product(&BigNumber::from_integer(2))
+            .quotient_to_precision(&root_pi, precision)
+            .unwrap();
+    }
+
+    /// Returns erfc(self) for large positive self to precision significant
+    /// digits.
+    ///
+    /// Evaluates erfc(x) = e^(-x^2) / (√π g) with the continued fraction
+    /// g = x + (1/2) / (x + 1 / (x + (3/2) / (x + ...))) using Lentz's method.
+    fn erfc_continued_fraction(self: &Self, precision: usize) -> BigNumber {
+        let working: usize = precision + GUARD_DIGITS;
+        let x: &BigNumber = self;
+        let one: BigNumber = BigNumber::one();
+        let mut fraction: BigNumber = x.clone();
+        let mut c: BigNumber = x.clone();
+        let mut d: BigNumber = BigNumber::zero();
+        let mut n: i128 = 1;
+        loop {
+            let a: BigNumber = BigNumber::from_integer(n).product(&BigNumber::half());
+            d = one
+                .quotient_to_precision(&x.sum(&a.product(&d)), working)
+                .unwrap();
+            c = x.sum(&a.quotient_to_precision(&c, working).unwrap());
+            let delta: BigNumber = c.product(&d).round_to_precision(working);
+            fraction = fraction.product(&delta).round_to_precision(working);
+            let change: BigNumber = delta.difference(&one);
+            if change.is_zero() || change.adjusted_exponent() < -(working as isize) {
+                break;
+            }
+            n += 1;
+        }
+        let root_pi: BigNumber = BigNumber::pi(working).sqrt(working).unwrap();
+        return x
+            .product(x)
+            .negated()
+            .exp(working)
+            .quotient_to_precision(&root_pi.product(&fraction), precision)
+            .unwrap();
+    }
+
+    /// Returns true if self^2 is larger than digits ln(10), which is when
+    /// e^(-self^2) drops below 10^-digits.
+    fn square_exceeds(self: &Self, digits: usize) -> bool {
+        let limit: BigNumber =
+            BigNumber::ln10(20).product(&BigNumber::from_integer(digits as i128));
+        return self.product(self) > limit;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_erf_erfc() {
+        let test_data = [
+            ("0", "0", "1"),
+            ("0.5", "0.52049987781304653768", "0.47950012218695346232"),
+            ("-1", "-0.84270079294971486934", "1.8427007929497148693"),
+            ("3", "0.99997790950300141456", "0.000022090496998585441373"),
+            (
+                "6",
+                "0.99999999999999997848",
+                "0.000000000000000021519736712498913117",
+            ),
+            (
+                "10",
+                "1.0000000000000000000",
+                "0.0000000000000000000000000000000000000000000020884875837625447570",
+            ),
+        ];
+        for (x, erf, erfc) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.erf(20), BigNumber::from_str(erf).unwrap());
+            assert_eq!(x.erfc(20), BigNumber::from_str(erfc).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_erfc_tail() {
+        // erfc(30) is about 2.56e-393, far below the smallest f64.
+        let tail: BigNumber = BigNumber::from_str("30").unwrap().erfc(15);
+        assert_eq!(tail.adjusted_exponent(), -393);
+        let expected: String = format!("0.{}256465620375611", "0".repeat(392));
+        assert_eq!(tail, BigNumber::from_str(&expected).unwrap());
+    }
+
+    #[test]
+    fn test_norm_cdf() {
+        let test_data = [
+            ("0", "0.5"),
+            ("1.96", "0.97500210485177956586"),
+            ("-1", "0.15865525393145705141"),
+            ("-10", "0.0000000000000000000000076198530241605260660"),
+            ("8.5", "0.99999999999999999052"),
+            ("20", "1"),
+            ("40", "1"),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.norm_cdf(20), BigNumber::from_str(expected).unwrap());
+        }
+
+        // Far into the lower tail the result keeps every digit.
+        let tail: BigNumber = BigNumber::from_str("-40").unwrap().norm_cdf(20);
+        let expected: String = format!("0.{}36558935409150297037", "0".repeat(349));
+        assert_eq!(tail, BigNumber::from_str(&expected).unwrap());
+    }
+
+    #[test]
+    fn test_norm_ppf() {
+        let test_data = [
+            ("0.5", Ok("0")),
+            ("0.975", Ok("1.9599639845400542355")),
+            ("0.025", Ok("-1.9599639845400542355")),
+            (
+                "0.000000000000000000000000000001",
+                Ok("-11.464024688443615727"),
+            ),
+            // Next to one half the quantile is about (p - 1/2)√(2π).
+            (
+                "0.5000000000000000000000000000000000000000000000000000000000001",
+                Ok("0.00000000000000000000000000000000000000000000000000000000000025066282746310005024"),
+            ),
+            ("0", Err(MathErrors::DomainError)),
+            ("1", Err(MathErrors::DomainError)),
+        ];
+        for (p, expected) in test_data {
+            let p = BigNumber::from_str(p).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(p.norm_ppf(20), expected);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// Extra significant digits carried through intermediate results so that the
+/// final rounding is not disturbed by the rounding of each step.
+pub const GUARD_DIGITS: usize = 10;
+
+impl BigNumber {
+    /// Returns e raised to the power of self rounded to precision significant
+    /// digits.
+    pub(crate) fn exp(self: &Self, precision: usize) -> BigNumber {
+        if self.is_zero() {
+            return BigNumber::one();
+        }
+        // Each squaring below doubles the relative error so the working
+        // precision grows with the number of squarings.
+        let working: usize =
+            precision + GUARD_DIGITS + cmp::max(0, self.adjusted_exponent() + 4) as usize;
+        if self.is_negative() {
+            return BigNumber::one()
+                .quotient_to_precision(&self.abs().exp(working), precision)
+                .unwrap();
+        }
+
+        // Halve the argument until the Taylor series converges quickly.
+        let half: BigNumber = BigNumber::half();
+        let limit: BigNumber = BigNumber::from_coefficient(Sign::Positive, vec![Digits::One], 3);
+        let mut reduced: BigNumber = self.clone();
+        let mut halvings: usize = 0;
+        while reduced > limit {
+            reduced = reduced.product(&half).round_to_precision(working);
+            halvings += 1;
+        }
+
+        let mut result: BigNumber = BigNumber::one();
+        let mut term: BigNumber = BigNumber::one();
+        let mut n: i128 = 1;
+        loop {
+            term = term
+                .product(&reduced)
+                .quotient(&BigNumber::from_integer(n), working + 3)
+                .unwrap();
+            if term.is_zero() {
+                break;
+            }
+            result = result.sum(&term);
+            n += 1;
+        }
+
+        for _ in 0..halvings {
+            result = result.product(&result).round_to_precision(working);
+        }
+        return result.round_to_precision(precision);
+    }
+
+    /// Returns the natural logarithm of self rounded to precision significant
+    /// digits.
+    ///
+    /// [MathErrors::DomainError] is returned if self is not positive.
+    pub(crate) fn ln(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        if self.is_negative() || self.is_zero() {
+            return Err(MathErrors::DomainError);
+        }
+        let one: BigNumber = BigNumber::one();
+        let two: BigNumber = BigNumber::from_integer(2);
+        let half: BigNumber = BigNumber::half();
+
+        // Arguments close to one are used directly. Everything else is written
+        // as m * 2^j * 10^e with m between 0.75 and 1.5.
+        let mut m: BigNumber = self.clone();
+        let mut exponent: isize = 0;
+        let mut twos: usize = 0;
+        if m < half || m > two {
+            exponent = m.adjusted_exponent();
+            let (coefficient, _) = m.coefficient();
+            let significant: Vec<Digits> = magnitude::significant(&coefficient).to_vec();
+            let scale: usize = significant.len() - 1;
+            m = BigNumber::from_coefficient(Sign::Positive, significant, scale);
+            let limit: BigNumber =
+                BigNumber::from_coefficient(Sign::Positive, vec![Digits::One, Digits::Five], 1);
+            while m > limit {
+                m = m.product(&half);
+                twos += 1;
+            }
+        }
+
+        let digits_of_exponent: usize = exponent.unsigned_abs().to_string().len();
+        let working: usize = precision + GUARD_DIGITS + digits_of_exponent;
+        // The logarithm of a number close to one is close to zero, so the
+        // series needs one more decimal place for every leading zero.
+        let distance: BigNumber = m.difference(&one);
+        if distance.is_zero() && exponent == 0 && twos == 0 {
+            return Ok(BigNumber::zero().round_to_precision(precision));
+        }
+        let scale: usize = working + cmp::max(0, -distance.adjusted_exponent()) as usize;
+        let z: BigNumber = distance.quotient(&m.sum(&one), scale)?;
+        let mut result: BigNumber = z.atanh_series(scale).product(&two);
+
+        if twos > 0 {
+            let ln2: BigNumber = BigNumber::ln2(working);
+            result = result.sum(&ln2.product(&BigNumber::from_integer(twos as i128)));
+        }
+        if exponent != 0 {
+            let ln10: BigNumber = BigNumber::ln10(working + 1);
+            result = result.sum(&ln10.product(&BigNumber::from_integer(exponent as i128)));
+        }
+        return Ok(result.round_to_precision(precision));
+    }
+
+    /// Returns the square root of self rounded to precision significant
+    /// digits.
+    ///
+    /// [MathErrors::DomainError] is returned if self is negative.
+    pub(crate) fn sqrt(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        if self.is_zero() {
+            return Ok(BigNumber::zero().round_to_precision(precision));
+        }
+        if self.is_negative() {
+            return Err(MathErrors::DomainError);
+        }
+        let (coefficient, scale) = self.coefficient();
+        let length: usize = magnitude::significant(&coefficient).len();
+        // The coefficient is padded to an even scale with enough digits for
+        // the integer square root to carry two digits more than requested.
+        let mut padding: usize = (2 * (precision + 2)).saturating_sub(length);
+        if (scale + padding) % 2 == 1 {
+            padding += 1;
+        }
+        let root: Vec<Digits> =
+            magnitude::square_root(&magnitude::shift_left(&coefficient, padding));
+        let mut result: BigNumber =
+            BigNumber::from_coefficient(Sign::Positive, root, (scale + padding) / 2);
+        // The integer square root truncates, so an inexact root must not be
+        // rounded as though it ended in a tie.
+        if result.product(&result).cmp(self) != Ordering::Equal {
+            let (mut root, root_scale) = result.coefficient();
+            root.push(Digits::One);
+            result = BigNumber::from_coefficient(Sign::Positive, root, root_scale + 1);
+        }
+        return Ok(result.round_to_precision(precision));
+    }
+
+    /// Returns the sum of self^(2k+1) / (2k+1) for k from zero, which is the
+    /// inverse hyperbolic tangent of self, at scale decimal places.
+    ///
+    /// self must be well inside the interval (-1, 1) for this to converge
+    /// quickly.
+    pub(crate) fn atanh_series(self: &Self, scale: usize) -> BigNumber {
+        let square: BigNumber = self.product(self).round_to_scale(scale + 2);
+        let mut power: BigNumber = self.clone();
+        let mut result: BigNumber = BigNumber::zero();
+        let mut k: i128 = 1;
+        loop {
+            let term: BigNumber = power
+                .quotient(&BigNumber::from_integer(k), scale + 2)
+                .unwrap();
+            if term.is_zero() {
+                break;
+            }
+            result = result.sum(&term);
+            power = power.product(&square).round_to_scale(scale + 2);
+            k += 2;
+        }
+        return result.round_to_scale(scale);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_exp() {
+        let test_data = [
+            ("0", 20, "1"),
+            ("1", 20, "2.7182818284590452354"),
+            ("-1", 20, "0.36787944117144232160"),
+            ("0.00000000000000000001", 20, "1.0000000000000000000"),
+            ("100", 10, "26881171420000000000000000000000000000000000"),
+            ("2.5", 15, "12.1824939607035"),
+        ];
+        for (x, precision, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.exp(precision), BigNumber::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ln() {
+        let test_data = [
+            ("1", 20, Ok("0")),
+            ("2", 20, Ok("0.69314718055994530942")),
+            ("10", 20, Ok("2.3025850929940456840")),
+            ("0.001", 20, Ok("-6.9077552789821370521")),
+            (
+                "1.000000000001",
+                20,
+                Ok("0.00000000000099999999999950000000"),
+            ),
+            ("123456.789", 15, Ok("11.7236464871859")),
+            ("0", 20, Err(MathErrors::DomainError)),
+            ("-3", 20, Err(MathErrors::DomainError)),
+        ];
+        for (x, precision, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.ln(precision), expected);
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let test_data = [
+            ("0", 10, Ok("0")),
+            ("4", 10, Ok("2")),
+            ("2", 20, Ok("1.4142135623730950488")),
+            ("0.0121", 5, Ok("0.11")),
+            ("1000000", 3, Ok("1000")),
+            ("-1", 10, Err(MathErrors::DomainError)),
+        ];
+        for (x, precision, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.sqrt(precision), expected);
+        }
+    }
+}
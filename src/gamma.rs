@@ -0,0 +1,416 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+
+// Inter crate imports
+use super::exponential::GUARD_DIGITS;
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// Multiplies x by y using single digit arithmetic whenever y allows it.
+fn multiply_u64(x: &[Digits], y: u64) -> Vec<Digits> {
+    if y <= u32::MAX as u64 {
+        return magnitude::multiply_small(x, y as u32);
+    }
+    return magnitude::multiply(x, &magnitude::from_u128(y as u128));
+}
+
+/// Divides x by y when y is already known to divide x exactly.
+fn divide_u64(x: &[Digits], y: u64) -> Vec<Digits> {
+    if y <= u32::MAX as u64 {
+        let (quotient, _) = magnitude::divide_small(x, y as u32).unwrap();
+        return quotient;
+    }
+    let (quotient, _) = magnitude::divide(x, &magnitude::from_u128(y as u128)).unwrap();
+    return quotient;
+}
+
+// Γ(n) for an integer n up to this is worked out as (n - 1)! exactly. 1000!
+// has 2568 digits and takes a moment, while larger factorials cost far more
+// than the few digits usually wanted from them.
+const EXACT_FACTORIAL_LIMIT: u128 = 1001;
+
+// The largest |ln Γ(x)| that gamma returns a value for. e^23000000 has just
+// under ten million digits, all of which the result would hold.
+const MAX_LN_GAMMA: i128 = 23_000_000;
+
+/// Returns the tangent numbers T(1) through T(count).
+///
+/// Uses the algorithm from Brent and Harvey, "Fast computation of Bernoulli,
+/// Tangent and Secant numbers", which needs nothing beyond integer additions
+/// and small multiplications.
+fn tangent_numbers(count: usize) -> Vec<Vec<Digits>> {
+    let mut tangents: Vec<Vec<Digits>> = vec![vec![Digits::One]; count];
+    for k in 1..count {
+        tangents[k] = magnitude::multiply_small(&tangents[k - 1], k as u32);
+    }
+    for k in 1..count {
+        for j in k..count {
+            tangents[j] = magnitude::add(
+                &magnitude::multiply_small(&tangents[j - 1], (j - k) as u32),
+                &magnitude::multiply_small(&tangents[j], (j - k + 2) as u32),
+            );
+        }
+    }
+    return tangents;
+}
+
+impl BigNumber {
+    /// Returns n! exactly.
+    pub fn factorial(n: u64) -> BigNumber {
+        let mut result: Vec<Digits> = vec![Digits::One];
+        // Consecutive factors are multiplied together while they still fit in
+        // a u32 so the long product is walked as few times as possible.
+        let mut batch: u64 = 1;
+        for factor in 2..=n {
+            if batch * factor > u32::MAX as u64 {
+                result = multiply_u64(&result, batch);
+                batch = 1;
+            }
+            batch *= factor;
+        }
+        result = multiply_u64(&result, batch);
+        return BigNumber::from_coefficient(Sign::Positive, result, 0);
+    }
+
+    /// Returns the number of ways to choose k items from n exactly.
+    ///
+    /// The result is zero when k is larger than n.
+    pub fn binomial(n: u64, k: u64) -> BigNumber {
+        if k > n {
+            return BigNumber::zero();
+        }
+        let k: u64 = cmp::min(k, n - k);
+        // After step i the running product is the binomial coefficient
+        // C(n - k + i, i), so every division is exact.
+        let mut result: Vec<Digits> = vec![Digits::One];
+        for i in 1..=k {
+            result = divide_u64(&multiply_u64(&result, n - k + i), i);
+        }
+        return BigNumber::from_coefficient(Sign::Positive, result, 0);
+    }
+
+    /// Returns the gamma function of self rounded to precision significant
+    /// digits.
+    ///
+    /// [MathErrors::DomainError] is returned if self is zero or a negative
+    /// integer and [MathErrors::Overflow] if the result would have about ten
+    /// million digits or more before or after the decimal point.
+    pub fn gamma(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        let precision: usize = cmp::max(precision, 1);
+        if self.is_integer() {
+            if self.is_negative() || self.is_zero() {
+                return Err(MathErrors::DomainError);
+            }
+            // Γ(n) = (n - 1)!
+            if let Some(n) = magnitude::to_u128(&self.integer) {
+                if n <= EXACT_FACTORIAL_LIMIT {
+                    return Ok(BigNumber::factorial(n as u64 - 1).round_to_precision(precision));
+                }
+            }
+        }
+        // Γ(x) = e^ln|Γ(x)| so the absolute error of the logarithm becomes
+        // the relative error of the result. Large logarithms need more digits
+        // to hold that absolute error.
+        let mut scale: usize = precision + GUARD_DIGITS;
+        let (mut logarithm, mut sign) = self.ln_gamma_with_sign(scale)?;
+        if logarithm.abs() > BigNumber::from_integer(MAX_LN_GAMMA) {
+            return Err(MathErrors::Overflow);
+        }
+        // A factorial with no more digits than are wanted is still worked out
+        // exactly, since ln 10 is less than three.
+        if self.is_integer() && logarithm < BigNumber::from_integer(3 * precision as i128) {
+            let n: u128 = magnitude::to_u128(&self.integer).unwrap();
+            return Ok(BigNumber::factorial(n as u64 - 1).round_to_precision(precision));
+        }
+        if logarithm.adjusted_exponent() > 0 {
+            scale += logarithm.adjusted_exponent() as usize;
+            (logarithm, sign) = self.ln_gamma_with_sign(scale)?;
+        }
+        let working: usize =
+            precision + GUARD_DIGITS + cmp::max(0, logarithm.adjusted_exponent() + 1) as usize;
+        let mut result: BigNumber = logarithm.exp(working).round_to_precision(precision);
+        result.sign = sign;
+        return Ok(result);
+    }
+
+    /// Returns the natural logarithm of the absolute value of the gamma
+    /// function of self rounded to precision significant digits.
+    ///
+    /// [MathErrors::DomainError] is returned if self is zero or a negative
+    /// integer.
+    pub fn ln_gamma(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        let precision: usize = cmp::max(precision, 1);
+        let mut scale: usize = precision + GUARD_DIGITS;
+        let (mut result, _) = self.ln_gamma_with_sign(scale)?;
+        // ln Γ(x) vanishes at 1 and 2 so results close to zero need more
+        // decimal places to keep precision significant digits.
+        if result.is_zero() {
+            return Ok(result);
+        }
+        if result.adjusted_exponent() < 0 {
+            scale += (-result.adjusted_exponent()) as usize;
+            (result, _) = self.ln_gamma_with_sign(scale)?;
+        }
+        return Ok(result.round_to_precision(precision));
+    }
+
+    /// Returns ln|Γ(self)| at scale decimal places along with the sign of
+    /// Γ(self).
+    fn ln_gamma_with_sign(self: &Self, scale: usize) -> Result<(BigNumber, Sign), MathErrors> {
+        if self.is_integer() && (self.is_negative() || self.is_zero()) {
+            return Err(MathErrors::DomainError);
+        }
+        let one: BigNumber = BigNumber::one();
+        if *self == one || *self == BigNumber::from_integer(2) {
+            return Ok((BigNumber::zero(), Sign::Positive));
+        }
+        let working: usize = scale + GUARD_DIGITS;
+        // Γ(x) Γ(1 - x) = π / sin(πx) takes arguments below one half to ones
+        // above it, where Γ is positive.
+        let half: BigNumber = BigNumber::half();
+        if *self < half {
+            let sine: BigNumber = self.sin_pi(working);
+            let (reflected, _) = one.difference(self).ln_gamma_with_sign(working)?;
+            // ln|sin(πx)| needs a few more significant digits when it is large.
+            let exponent: isize = sine.adjusted_exponent();
+            let digits: usize = working + exponent.unsigned_abs().to_string().len() + 1;
+            let result: BigNumber = BigNumber::pi(working + 1)
+                .ln(working + 1)?
+                .difference(&sine.abs().ln(digits)?)
+                .difference(&reflected);
+            return Ok((result.round_to_scale(scale), sine.sign));
+        }
+        // The Stirling series below is accurate to about e^(-2πz), so small
+        // arguments are first moved up with Γ(x) = Γ(x + n) / (x (x + 1) ...
+        // (x + n - 1)).
+        let minimum: BigNumber = BigNumber::from_integer((working * 2 / 5 + 2) as i128);
+        let mut z: BigNumber = self.clone();
+        let mut shift_product: BigNumber = one.clone();
+        while z < minimum {
+            shift_product = shift_product
+                .product(&z)
+                .round_to_precision(working + GUARD_DIGITS);
+            z = z.sum(&one);
+        }
+
+        let mut result: BigNumber = z.stirling(working);
+        if shift_product != one {
+            // ln P needs a few more significant digits when it is large.
+            let exponent: isize = shift_product.adjusted_exponent();
+            let digits: usize = working + exponent.unsigned_abs().to_string().len() + 1;
+            result = result.difference(&shift_product.ln(digits)?);
+        }
+        return Ok((result.round_to_scale(scale), Sign::Positive));
+    }
+
+    /// Returns sin(π self) rounded to precision significant digits.
+    ///
+    /// self must not be an integer.
+    fn sin_pi(self: &Self, precision: usize) -> BigNumber {
+        let one: BigNumber = BigNumber::one();
+        // sin(πx) repeats every two units and changes sign every unit, so
+        // only the distance from x to the nearest integer is needed, which is
+        // exact for a decimal x.
+        let mut distance: BigNumber = self.rem_euclid(&BigNumber::from_integer(2)).unwrap();
+        let mut sign: Sign = Sign::Positive;
+        if distance >= one {
+            distance = distance.difference(&one);
+            sign = Sign::Negative;
+        }
+        if distance > BigNumber::half() {
+            distance = one.difference(&distance);
+        }
+        // Enough decimal places to keep precision significant digits of a
+        // small result.
+        let working: usize = precision + GUARD_DIGITS;
+        let x: BigNumber = BigNumber::pi(working)
+            .product(&distance)
+            .round_to_precision(working);
+        let scale: usize = working + x.adjusted_exponent().unsigned_abs();
+        let square: BigNumber = x.product(&x).round_to_scale(scale);
+
+        // x - x^3 / 3! + x^5 / 5! - ...
+        let mut result: BigNumber = x.clone();
+        let mut term: BigNumber = x;
+        let mut n: i128 = 1;
+        loop {
+            term = term
+                .product(&square)
+                .quotient(&BigNumber::from_integer((n + 1) * (n + 2)), scale)
+                .unwrap()
+                .negated();
+            if term.is_zero() {
+                break;
+            }
+            result = result.sum(&term);
+            n += 2;
+        }
+        result.sign = sign;
+        return result.round_to_precision(precision);
+    }
+
+    /// Returns ln Γ(self) at scale decimal places using the Stirling series.
+    ///
+    /// self must be large enough for the series to reach that accuracy.
+    fn stirling(self: &Self, scale: usize) -> BigNumber {
+        let z: &BigNumber = self;
+        let one: BigNumber = BigNumber::one();
+        let z_digits: usize = cmp::max(0, z.adjusted_exponent() + 1) as usize;
+        let working: usize = scale + GUARD_DIGITS + 2 * z_digits;
+
+        // (z - 1/2) ln z - z + ln(2π) / 2
+        let ln_z: BigNumber = z.ln(working).unwrap();
+        let two_pi: BigNumber = BigNumber::pi(working).product(&BigNumber::from_integer(2));
+        let mut result: BigNumber = z
+            .difference(&BigNumber::half())
+            .product(&ln_z)
+            .difference(z)
+            .sum(&two_pi.ln(working).unwrap().product(&BigNumber::half()))
+            .round_to_scale(working);
+
+        // The sum of B(2k) / (2k (2k - 1) z^(2k - 1)) where
+        // B(2k) = (-1)^(k - 1) 2k T(k) / (4^k (4^k - 1)).
+        let inverse: BigNumber = one.quotient(z, working).unwrap();
+        let inverse_square: BigNumber = inverse.product(&inverse).round_to_scale(working);
+        let terms: usize = (working * 3 / 2) + 2;
+        let tangents: Vec<Vec<Digits>> = tangent_numbers(terms);
+        let mut power: BigNumber = inverse;
+        let mut four_k: BigNumber = one.clone();
+        let mut previous: Option<BigNumber> = None;
+        for (index, tangent) in tangents.iter().enumerate() {
+            let k: i128 = index as i128 + 1;
+            four_k = four_k.product(&BigNumber::from_integer(4));
+            let denominator: BigNumber = BigNumber::from_integer(2 * k - 1)
+                .product(&four_k)
+                .product(&four_k.difference(&one));
+            let mut term: BigNumber =
+                BigNumber::from_coefficient(Sign::Positive, tangent.clone(), 0)
+                    .product(&power)
+                    .quotient(&denominator, working)
+                    .unwrap();
+            if term.is_zero() {
+                break;
+            }
+            // The series is asymptotic, so stop before the terms grow again.
+            if previous.as_ref().is_some_and(|p| term > *p) {
+                break;
+            }
+            previous = Some(term.clone());
+            if k % 2 == 0 {
+                term.negate();
+            }
+            result = result.sum(&term);
+            power = power.product(&inverse_square).round_to_scale(working);
+        }
+        return result.round_to_scale(scale);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_factorial() {
+        let test_data = [
+            (0, "1"),
+            (1, "1"),
+            (20, "2432902008176640000"),
+            (30, "265252859812191058636308480000000"),
+        ];
+        for (n, expected) in test_data {
+            assert_eq!(
+                BigNumber::factorial(n),
+                BigNumber::from_str(expected).unwrap()
+            );
+        }
+        let large: String = BigNumber::factorial(1000).to_string();
+        assert_eq!(large.len(), 2568);
+        assert!(large.starts_with("402387260077093773543702433923003985719374864210"));
+    }
+
+    #[test]
+    fn test_binomial() {
+        let test_data = [
+            (0, 0, "1"),
+            (10, 3, "120"),
+            (10, 7, "120"),
+            (5, 7, "0"),
+            (100, 50, "100891344545564193334812497256"),
+        ];
+        for (n, k, expected) in test_data {
+            assert_eq!(
+                BigNumber::binomial(n, k),
+                BigNumber::from_str(expected).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_gamma() {
+        let test_data = [
+            ("5", Ok("24")),
+            ("0.5", Ok("1.7724538509055160273")),
+            ("-0.5", Ok("-3.5449077018110320546")),
+            ("-2.5", Ok("-0.94530872048294188123")),
+            ("0", Err(MathErrors::DomainError)),
+            ("-3", Err(MathErrors::DomainError)),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.gamma(20), expected);
+        }
+        let large: String = format!("93209631040827166083{}", "0".repeat(137));
+        assert_eq!(
+            BigNumber::from_str("100.5").unwrap().gamma(20),
+            Ok(BigNumber::from_str(&large).unwrap())
+        );
+
+        // Large integers are not worked out as exact factorials.
+        // (n, leading digits, number of digits)
+        let test_data = [
+            ("1002", "40278964733717086732", 2571),
+            ("100000", "28242294079603478743", 456569),
+        ];
+        for (n, digits, length) in test_data {
+            let result: String = BigNumber::from_str(n)
+                .unwrap()
+                .gamma(20)
+                .unwrap()
+                .to_string();
+            assert_eq!(result, format!("{digits}{}", "0".repeat(length - 20)));
+        }
+
+        let x = BigNumber::from_str("-99999999999999999999.5").unwrap();
+        assert_eq!(x.gamma(20), Err(MathErrors::Overflow));
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        let test_data = [
+            ("1", Ok("0")),
+            ("2", Ok("0")),
+            ("0.5", Ok("0.57236494292470008707")),
+            ("1.0001", Ok("-0.000057713342220477623308")),
+            ("-2.5", Ok("-0.056243716497674050673")),
+            ("1000.5", Ok("5908.6741758486774887")),
+            ("0.25", Ok("1.2880225246980774574")),
+            ("-0.001", Ok("6.9083333175150284318")),
+            ("-3.0001", Ok("7.4184553060115238389")),
+            ("-1000000.5", Ok("-12815524.147683937757")),
+            ("-99999999999999999999.5", Ok("-4505170185988091368000")),
+            ("-1", Err(MathErrors::DomainError)),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.ln_gamma(20), expected);
+        }
+    }
+}
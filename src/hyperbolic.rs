@@ -0,0 +1,265 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::exponential::GUARD_DIGITS;
+use super::BigNumber;
+use super::MathErrors;
+
+impl BigNumber {
+    /// Returns the hyperbolic sine of self rounded to precision significant
+    /// digits.
+    pub fn sinh(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        if self.is_zero() {
+            return BigNumber::zero();
+        }
+        let working: usize = precision + GUARD_DIGITS;
+        if self.abs() < BigNumber::one() {
+            // (e^x - e^-x) / 2 cancels badly for small x, so sum the Taylor
+            // series instead.
+            return self.sinh_series(working).round_to_precision(precision);
+        }
+        let ex: BigNumber = self.exp(working);
+        let inverse: BigNumber = BigNumber::one()
+            .quotient_to_precision(&ex, working)
+            .unwrap();
+        return ex
+            .difference(&inverse)
+            .product(&BigNumber::half())
+            .round_to_precision(precision);
+    }
+
+    /// Returns the hyperbolic cosine of self rounded to precision significant
+    /// digits.
+    pub fn cosh(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        let working: usize = precision + GUARD_DIGITS;
+        let ex: BigNumber = self.exp(working);
+        let inverse: BigNumber = BigNumber::one()
+            .quotient_to_precision(&ex, working)
+            .unwrap();
+        return ex
+            .sum(&inverse)
+            .product(&BigNumber::half())
+            .round_to_precision(precision);
+    }
+
+    /// Returns the hyperbolic tangent of self rounded to precision significant
+    /// digits.
+    pub fn tanh(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        if self.is_zero() {
+            return BigNumber::zero();
+        }
+        let working: usize = precision + GUARD_DIGITS;
+        if self.abs() < BigNumber::one() {
+            let sinh: BigNumber = self.sinh_series(working);
+            let cosh: BigNumber = self.cosh(working);
+            return sinh.quotient_to_precision(&cosh, precision).unwrap();
+        }
+        // tanh(x) = 1 - 2 / (e^2x + 1) for positive x.
+        let two: BigNumber = BigNumber::from_integer(2);
+        let e2x: BigNumber = self.abs().product(&two).exp(working);
+        let mut result: BigNumber = BigNumber::one().difference(
+            &two.quotient_to_precision(&e2x.sum(&BigNumber::one()), working)
+                .unwrap(),
+        );
+        if self.is_negative() {
+            result.negate();
+        }
+        return result.round_to_precision(precision);
+    }
+
+    /// Returns the inverse hyperbolic sine of self rounded to precision
+    /// significant digits.
+    pub fn asinh(self: &Self, precision: usize) -> BigNumber {
+        let precision: usize = cmp::max(precision, 1);
+        if self.is_zero() {
+            return BigNumber::zero();
+        }
+        // asinh(x) = ln(x + sqrt(x^2 + 1)) is close to x for small x, so the
+        // argument of the logarithm needs a digit more for each leading zero.
+        let working: usize =
+            precision + GUARD_DIGITS + cmp::max(0, -self.adjusted_exponent()) as usize;
+        let x: BigNumber = self.abs();
+        let root: BigNumber = x.product(&x).sum(&BigNumber::one()).sqrt(working).unwrap();
+        let mut result: BigNumber = x.sum(&root).ln(precision + GUARD_DIGITS).unwrap();
+        if self.is_negative() {
+            result.negate();
+        }
+        return result.round_to_precision(precision);
+    }
+
+    /// Returns the inverse hyperbolic cosine of self rounded to precision
+    /// significant digits.
+    ///
+    /// [MathErrors::DomainError] is returned if self is less than one.
+    pub fn acosh(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        let precision: usize = cmp::max(precision, 1);
+        let one: BigNumber = BigNumber::one();
+        match self.cmp(&one) {
+            Ordering::Less => return Err(MathErrors::DomainError),
+            Ordering::Equal => return Ok(BigNumber::zero()),
+            Ordering::Greater => {}
+        }
+        // acosh(x) = ln(x + sqrt(x^2 - 1)) is close to zero for x close to one.
+        let distance: BigNumber = self.difference(&one);
+        let working: usize =
+            precision + GUARD_DIGITS + cmp::max(0, -distance.adjusted_exponent()) as usize;
+        let root: BigNumber = self.product(self).difference(&one).sqrt(working)?;
+        let result: BigNumber = self.sum(&root).ln(precision + GUARD_DIGITS)?;
+        return Ok(result.round_to_precision(precision));
+    }
+
+    /// Returns the inverse hyperbolic tangent of self rounded to precision
+    /// significant digits.
+    ///
+    /// [MathErrors::DomainError] is returned unless self is strictly between
+    /// -1 and 1.
+    pub fn atanh(self: &Self, precision: usize) -> Result<BigNumber, MathErrors> {
+        let precision: usize = cmp::max(precision, 1);
+        let one: BigNumber = BigNumber::one();
+        if self.abs() >= one {
+            return Err(MathErrors::DomainError);
+        }
+        if self.is_zero() {
+            return Ok(BigNumber::zero());
+        }
+        let working: usize = precision + GUARD_DIGITS;
+        if self.abs() <= BigNumber::half() {
+            let scale: usize = (working as isize - self.adjusted_exponent()) as usize;
+            return Ok(self.atanh_series(scale).round_to_precision(precision));
+        }
+        // atanh(x) = ln((1 + x) / (1 - x)) / 2
+        let ratio: BigNumber = one
+            .sum(self)
+            .quotient_to_precision(&one.difference(self), working)?;
+        let result: BigNumber = ratio.ln(working)?.product(&BigNumber::half());
+        return Ok(result.round_to_precision(precision));
+    }
+
+    /// Returns the sum of x^(2k+1) / (2k+1)! for k from zero, which is the
+    /// hyperbolic sine of self, to precision significant digits.
+    ///
+    /// Only intended for self between -1 and 1.
+    fn sinh_series(self: &Self, precision: usize) -> BigNumber {
+        let scale: usize = (precision as isize - self.adjusted_exponent() + 1) as usize;
+        let square: BigNumber = self.product(self).round_to_scale(scale + 2);
+        let mut term: BigNumber = self.clone();
+        let mut result: BigNumber = BigNumber::zero();
+        let mut k: i128 = 1;
+        while !term.is_zero() {
+            result = result.sum(&term);
+            term = term
+                .product(&square)
+                .quotient(&BigNumber::from_integer((k + 1) * (k + 2)), scale + 2)
+                .unwrap();
+            k += 2;
+        }
+        return result.round_to_precision(precision);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_sinh_cosh_tanh() {
+        let test_data = [
+            ("0", "0", "1", "0"),
+            (
+                "1",
+                "1.1752011936438014569",
+                "1.5430806348152437785",
+                "0.76159415595576488812",
+            ),
+            (
+                "-0.5",
+                "-0.52109530549374736162",
+                "1.1276259652063807852",
+                "-0.46211715726000975850",
+            ),
+            (
+                "0.00000000000000000001",
+                "0.000000000000000000010000000000000000000",
+                "1.0000000000000000000",
+                "0.000000000000000000010000000000000000000",
+            ),
+            (
+                "10",
+                "11013.232874703393377",
+                "11013.232920103323140",
+                "0.99999999587769276362",
+            ),
+        ];
+        for (x, sinh, cosh, tanh) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.sinh(20), BigNumber::from_str(sinh).unwrap());
+            assert_eq!(x.cosh(20), BigNumber::from_str(cosh).unwrap());
+            assert_eq!(x.tanh(20), BigNumber::from_str(tanh).unwrap());
+        }
+
+        // Results that round up to ±1 keep the requested number of digits.
+        let test_data = [
+            ("100", "1.0000000000000000000"),
+            ("-100", "-1.0000000000000000000"),
+            ("30", "1.0000000000000000000"),
+        ];
+        for (x, tanh) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.tanh(20).to_string(), tanh);
+        }
+    }
+
+    #[test]
+    fn test_asinh() {
+        let test_data = [
+            ("0", "0"),
+            ("1", "0.88137358701954302523"),
+            ("-2", "-1.4436354751788103425"),
+            ("0.000000000001", "0.0000000000010000000000000000000"),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.asinh(20), BigNumber::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_acosh() {
+        let test_data = [
+            ("1", Ok("0")),
+            ("2", Ok("1.3169578969248167086")),
+            ("1.000000000001", Ok("0.0000014142135623729771977")),
+            ("0.5", Err(MathErrors::DomainError)),
+            ("-2", Err(MathErrors::DomainError)),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.acosh(20), expected);
+        }
+    }
+
+    #[test]
+    fn test_atanh() {
+        let test_data = [
+            ("0", Ok("0")),
+            ("0.5", Ok("0.54930614433405484570")),
+            ("-0.9", Ok("-1.4722194895832202300")),
+            ("0.999999", Ok("7.2543286192620472067")),
+            ("1", Err(MathErrors::DomainError)),
+            ("-1.5", Err(MathErrors::DomainError)),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.atanh(20), expected);
+        }
+    }
+}
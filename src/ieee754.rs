@@ -0,0 +1,699 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// The parameters of an IEEE 754-2008 decimal interchange format.
+struct Format {
+    // Digits in the coefficient.
+    precision: usize,
+    // Subtracted from the stored exponent to get the power of ten of the
+    // last coefficient digit.
+    bias: isize,
+    // Bits of the exponent stored outside the combination field.
+    continuation: u32,
+    // Ten bit groups in the trailing significand.
+    declets: u32,
+}
+
+const DECIMAL32: Format = Format {
+    precision: 7,
+    bias: 101,
+    continuation: 6,
+    declets: 2,
+};
+
+const DECIMAL64: Format = Format {
+    precision: 16,
+    bias: 398,
+    continuation: 8,
+    declets: 5,
+};
+
+const DECIMAL128: Format = Format {
+    precision: 34,
+    bias: 6176,
+    continuation: 12,
+    declets: 11,
+};
+
+impl Format {
+    fn width(self: &Self) -> u32 {
+        return 6 + self.continuation + self.trailing();
+    }
+
+    fn trailing(self: &Self) -> u32 {
+        return 10 * self.declets;
+    }
+
+    fn max_exponent(self: &Self) -> isize {
+        return (3 << self.continuation) - 1 - self.bias;
+    }
+
+    fn min_exponent(self: &Self) -> isize {
+        return -self.bias;
+    }
+}
+
+/// How the coefficient is stored.
+#[derive(Clone, Copy, PartialEq)]
+enum Encoding {
+    // Binary integer decimal, the coefficient as one binary integer.
+    Bid,
+    // Densely packed decimal, three digits in every ten bits.
+    Dpd,
+}
+
+/// A value of an IEEE 754-2008 decimal format, which can also be NaN or
+/// infinite.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IeeeDecimal {
+    Number(BigNumber),
+    NaN,
+    Infinity,
+    NegativeInfinity,
+}
+
+impl From<BigNumber> for IeeeDecimal {
+    fn from(input: BigNumber) -> Self {
+        return IeeeDecimal::Number(input);
+    }
+}
+
+// The combination field values of the special values.
+const INFINITY: u128 = 0b11110;
+const NAN: u128 = 0b11111;
+
+/// Packs three decimal digits into ten bits.
+fn encode_declet(n: u16) -> u16 {
+    let (first, second, third) = (n / 100, n / 10 % 10, n % 10);
+    let bit = |digit: u16, position: u16| -> u16 {
+        return (digit >> position) & 1;
+    };
+    let (b, c, d) = (bit(first, 2), bit(first, 1), bit(first, 0));
+    let (f, g, h) = (bit(second, 2), bit(second, 1), bit(second, 0));
+    let (j, k, m) = (bit(third, 2), bit(third, 1), bit(third, 0));
+    let bits: [u16; 10] = match (first > 7, second > 7, third > 7) {
+        (false, false, false) => [b, c, d, f, g, h, 0, j, k, m],
+        (false, false, true) => [b, c, d, f, g, h, 1, 0, 0, m],
+        (false, true, false) => [b, c, d, j, k, h, 1, 0, 1, m],
+        (false, true, true) => [b, c, d, 1, 0, h, 1, 1, 1, m],
+        (true, false, false) => [j, k, d, f, g, h, 1, 1, 0, m],
+        (true, false, true) => [f, g, d, 0, 1, h, 1, 1, 1, m],
+        (true, true, false) => [j, k, d, 0, 0, h, 1, 1, 1, m],
+        (true, true, true) => [0, 0, d, 1, 1, h, 1, 1, 1, m],
+    };
+    return bits.iter().fold(0, |total, bit| total << 1 | bit);
+}
+
+/// Unpacks ten bits into three decimal digits. Every one of the 1024 bit
+/// patterns gives a value below 1000.
+fn decode_declet(bits: u16) -> u16 {
+    let bit = |position: u16| -> u16 {
+        return (bits >> position) & 1;
+    };
+    let (p, q, r) = (bit(9), bit(8), bit(7));
+    let (s, t, u) = (bit(6), bit(5), bit(4));
+    let (w, x, y) = (bit(2), bit(1), bit(0));
+    let pqr: u16 = p << 2 | q << 1 | r;
+    let stu: u16 = s << 2 | t << 1 | u;
+    let (first, second, third) = if bit(3) == 0 {
+        (pqr, stu, w << 2 | x << 1 | y)
+    } else {
+        match (w, x, s, t) {
+            (0, 0, _, _) => (pqr, stu, 8 + y),
+            (0, 1, _, _) => (pqr, 8 + u, s << 2 | t << 1 | y),
+            (1, 0, _, _) => (8 + r, stu, p << 2 | q << 1 | y),
+            (_, _, 0, 0) => (8 + r, 8 + u, p << 2 | q << 1 | y),
+            (_, _, 0, 1) => (8 + r, p << 2 | q << 1 | u, 8 + y),
+            (_, _, 1, 0) => (pqr, 8 + u, 8 + y),
+            _ => (8 + r, 8 + u, 8 + y),
+        }
+    };
+    return first * 100 + second * 10 + third;
+}
+
+/// Rounds x half to even to the precision and exponent range of the format,
+/// returning the coefficient and the biased exponent.
+///
+/// Values too small for the format lose digits down to the smallest
+/// exponent and can become zero. [MathErrors::Overflow] is returned if the
+/// value is too large.
+fn round_to_format(x: &BigNumber, format: &Format) -> Result<(u128, u128), MathErrors> {
+    let (coefficient, scale) = x.coefficient();
+    let mut digits: Vec<Digits> = magnitude::significant(&coefficient).to_vec();
+    let mut exponent: isize = -(scale as isize);
+    // Digits past the precision and digits below the smallest exponent are
+    // dropped together, so the value is only rounded once.
+    let excess: usize = digits.len().saturating_sub(format.precision);
+    let target: isize = cmp::max(exponent + excess as isize, format.min_exponent());
+    if target > exponent {
+        digits = magnitude::round_half_even(&digits, (target - exponent) as usize);
+        exponent = target;
+        // Rounding up 99…9 gives a one followed by precision zeros.
+        if digits.len() > format.precision {
+            digits.pop();
+            exponent += 1;
+        }
+    }
+    if exponent > format.max_exponent() {
+        // Trailing zeros can be added to bring the exponent into range as
+        // long as the coefficient still fits.
+        let padding: usize = (exponent - format.max_exponent()) as usize;
+        if !magnitude::is_zero(&digits) && digits.len() + padding > format.precision {
+            return Err(MathErrors::Overflow);
+        }
+        digits = magnitude::shift_left(&digits, padding);
+        exponent = format.max_exponent();
+    }
+    // At most 34 digits, which always fits.
+    let coefficient: u128 = magnitude::to_u128(&digits).unwrap();
+    return Ok((coefficient, (exponent + format.bias) as u128));
+}
+
+/// Encodes x in the format, giving an infinity if x is too large.
+fn encode(x: &BigNumber, format: &Format, encoding: Encoding) -> u128 {
+    return match try_encode(x, format, encoding) {
+        Ok(bits) => bits,
+        Err(_) => sign_bit(x, format) | INFINITY << (format.width() - 6),
+    };
+}
+
+fn sign_bit(x: &BigNumber, format: &Format) -> u128 {
+    return (x.is_negative() as u128) << (format.width() - 1);
+}
+
+/// Encodes x as a decimal128 with a binary integer coefficient, returning
+/// [MathErrors::Overflow] instead of an infinity if x is too large.
+#[cfg(feature = "bson")]
+pub(crate) fn try_to_decimal128_bid(x: &BigNumber) -> Result<u128, MathErrors> {
+    return try_encode(x, &DECIMAL128, Encoding::Bid);
+}
+
+/// Encodes x in the format, returning [MathErrors::Overflow] if x is too
+/// large.
+fn try_encode(x: &BigNumber, format: &Format, encoding: Encoding) -> Result<u128, MathErrors> {
+    let sign: u128 = sign_bit(x, format);
+    let (coefficient, exponent) = round_to_format(x, format)?;
+    let trailing: u32 = format.trailing();
+    if encoding == Encoding::Bid {
+        if coefficient >> (trailing + 3) == 0 {
+            return Ok(sign | exponent << (trailing + 3) | coefficient);
+        }
+        // The coefficient starts with the bits 100, which are left out.
+        return Ok(sign
+            | 0b11 << (format.width() - 3)
+            | exponent << (trailing + 1)
+            | coefficient & ((1 << (trailing + 1)) - 1));
+    }
+
+    let mut declets: u128 = 0;
+    let mut remaining: u128 = coefficient;
+    for index in 0..format.declets {
+        declets |= (encode_declet((remaining % 1000) as u16) as u128) << (10 * index);
+        remaining /= 1000;
+    }
+    // The combination field holds the two high exponent bits and the
+    // leading digit.
+    let high: u128 = exponent >> format.continuation;
+    let combination: u128 = if remaining < 8 {
+        high << 3 | remaining
+    } else {
+        0b11000 | high << 1 | (remaining & 1)
+    };
+    let low: u128 = exponent & ((1 << format.continuation) - 1);
+    return Ok(sign | combination << (format.width() - 6) | low << trailing | declets);
+}
+
+/// Encodes value in the format, see [encode] for the numbers.
+fn encode_value(value: &IeeeDecimal, format: &Format, encoding: Encoding) -> u128 {
+    let width: u32 = format.width();
+    match value {
+        IeeeDecimal::Number(x) => return encode(x, format, encoding),
+        IeeeDecimal::NaN => return NAN << (width - 6),
+        IeeeDecimal::Infinity => return INFINITY << (width - 6),
+        IeeeDecimal::NegativeInfinity => return 1 << (width - 1) | INFINITY << (width - 6),
+    }
+}
+
+/// Decodes bits in the format.
+///
+/// Coefficients too large for the format decode as zero, as the standard
+/// requires.
+fn decode(bits: u128, format: &Format, encoding: Encoding) -> IeeeDecimal {
+    let width: u32 = format.width();
+    let trailing: u32 = format.trailing();
+    let sign: Sign = if (bits >> (width - 1)) & 1 == 1 {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    let combination: u128 = (bits >> (width - 6)) & 0b11111;
+    if combination == NAN {
+        return IeeeDecimal::NaN;
+    }
+    if combination == INFINITY {
+        if sign.is_negative() {
+            return IeeeDecimal::NegativeInfinity;
+        }
+        return IeeeDecimal::Infinity;
+    }
+
+    let exponent: u128;
+    let mut digits: Vec<Digits>;
+    if encoding == Encoding::Bid {
+        let mut coefficient: u128;
+        if combination >> 3 == 0b11 {
+            exponent = (bits >> (trailing + 1)) & ((1 << (format.continuation + 2)) - 1);
+            coefficient = 0b100 << (trailing + 1) | bits & ((1 << (trailing + 1)) - 1);
+        } else {
+            exponent = (bits >> (trailing + 3)) & ((1 << (format.continuation + 2)) - 1);
+            coefficient = bits & ((1 << (trailing + 3)) - 1);
+        }
+        if coefficient >= 10u128.pow(format.precision as u32) {
+            coefficient = 0;
+        }
+        digits = magnitude::from_u128(coefficient);
+    } else {
+        let (high, leading) = if combination >> 3 == 0b11 {
+            ((combination >> 1) & 0b11, 8 + (combination & 1))
+        } else {
+            (combination >> 3, combination & 0b111)
+        };
+        let low: u128 = (bits >> trailing) & ((1 << format.continuation) - 1);
+        exponent = high << format.continuation | low;
+        digits = vec![magnitude::digit(leading as usize)];
+        for index in (0..format.declets).rev() {
+            let declet: u16 = decode_declet(((bits >> (10 * index)) & 0x3FF) as u16);
+            // Adding 1000 keeps the leading zeros of the group.
+            digits.extend_from_slice(&magnitude::from_u128(declet as u128 + 1000)[1..]);
+        }
+    }
+
+    let power: isize = exponent as isize - format.bias;
+    if power >= 0 {
+        digits.resize(digits.len() + power as usize, Digits::Zero);
+        return IeeeDecimal::Number(BigNumber::from_coefficient(sign, digits, 0));
+    }
+    return IeeeDecimal::Number(BigNumber::from_coefficient(
+        sign,
+        digits,
+        power.unsigned_abs(),
+    ));
+}
+
+/// Returns the number in value, or [MathErrors::DomainError] for NaN and the
+/// infinities.
+fn finite(value: IeeeDecimal) -> Result<BigNumber, MathErrors> {
+    match value {
+        IeeeDecimal::Number(x) => return Ok(x),
+        _ => return Err(MathErrors::DomainError),
+    }
+}
+
+// Conversions of every value the IEEE 754-2008 decimal interchange formats
+// hold. NaN is read the same whether it is quiet or signaling and written
+// as a quiet NaN.
+impl IeeeDecimal {
+    /// Encodes the value as a decimal32 with a binary integer coefficient.
+    pub fn to_decimal32_bid(self: &Self) -> u32 {
+        return encode_value(self, &DECIMAL32, Encoding::Bid) as u32;
+    }
+
+    /// Encodes the value as a decimal32 with a densely packed decimal
+    /// coefficient.
+    pub fn to_decimal32_dpd(self: &Self) -> u32 {
+        return encode_value(self, &DECIMAL32, Encoding::Dpd) as u32;
+    }
+
+    /// Encodes the value as a decimal64 with a binary integer coefficient.
+    pub fn to_decimal64_bid(self: &Self) -> u64 {
+        return encode_value(self, &DECIMAL64, Encoding::Bid) as u64;
+    }
+
+    /// Encodes the value as a decimal64 with a densely packed decimal
+    /// coefficient.
+    pub fn to_decimal64_dpd(self: &Self) -> u64 {
+        return encode_value(self, &DECIMAL64, Encoding::Dpd) as u64;
+    }
+
+    /// Encodes the value as a decimal128 with a binary integer coefficient.
+    pub fn to_decimal128_bid(self: &Self) -> u128 {
+        return encode_value(self, &DECIMAL128, Encoding::Bid);
+    }
+
+    /// Encodes the value as a decimal128 with a densely packed decimal
+    /// coefficient.
+    pub fn to_decimal128_dpd(self: &Self) -> u128 {
+        return encode_value(self, &DECIMAL128, Encoding::Dpd);
+    }
+
+    /// Decodes a decimal32 with a binary integer coefficient.
+    pub fn from_decimal32_bid(bits: u32) -> IeeeDecimal {
+        return decode(bits as u128, &DECIMAL32, Encoding::Bid);
+    }
+
+    /// Decodes a decimal32 with a densely packed decimal coefficient.
+    pub fn from_decimal32_dpd(bits: u32) -> IeeeDecimal {
+        return decode(bits as u128, &DECIMAL32, Encoding::Dpd);
+    }
+
+    /// Decodes a decimal64 with a binary integer coefficient.
+    pub fn from_decimal64_bid(bits: u64) -> IeeeDecimal {
+        return decode(bits as u128, &DECIMAL64, Encoding::Bid);
+    }
+
+    /// Decodes a decimal64 with a densely packed decimal coefficient.
+    pub fn from_decimal64_dpd(bits: u64) -> IeeeDecimal {
+        return decode(bits as u128, &DECIMAL64, Encoding::Dpd);
+    }
+
+    /// Decodes a decimal128 with a binary integer coefficient.
+    pub fn from_decimal128_bid(bits: u128) -> IeeeDecimal {
+        return decode(bits, &DECIMAL128, Encoding::Bid);
+    }
+
+    /// Decodes a decimal128 with a densely packed decimal coefficient.
+    pub fn from_decimal128_dpd(bits: u128) -> IeeeDecimal {
+        return decode(bits, &DECIMAL128, Encoding::Dpd);
+    }
+}
+
+// Conversions to and from the IEEE 754-2008 decimal interchange formats.
+//
+// Encoding rounds half to even to the precision of the format, 7, 16 or 34
+// digits, and keeps the exponent of self where it can, so 1.50 is stored as
+// 150 times 10^-2. Values too small for the format lose digits, rounding half
+// to even, down to the smallest exponent, and values too large become an
+// infinity of the same sign.
+//
+// Decoding gives the exact value with the stored exponent, and
+// [MathErrors::DomainError] for NaN and the infinities. Read an
+// [IeeeDecimal] to get those.
+impl BigNumber {
+    /// Encodes self as a decimal32 with a binary integer coefficient.
+    pub fn to_decimal32_bid(self: &Self) -> u32 {
+        return encode(self, &DECIMAL32, Encoding::Bid) as u32;
+    }
+
+    /// Encodes self as a decimal32 with a densely packed decimal coefficient.
+    pub fn to_decimal32_dpd(self: &Self) -> u32 {
+        return encode(self, &DECIMAL32, Encoding::Dpd) as u32;
+    }
+
+    /// Encodes self as a decimal64 with a binary integer coefficient.
+    pub fn to_decimal64_bid(self: &Self) -> u64 {
+        return encode(self, &DECIMAL64, Encoding::Bid) as u64;
+    }
+
+    /// Encodes self as a decimal64 with a densely packed decimal coefficient.
+    pub fn to_decimal64_dpd(self: &Self) -> u64 {
+        return encode(self, &DECIMAL64, Encoding::Dpd) as u64;
+    }
+
+    /// Encodes self as a decimal128 with a binary integer coefficient.
+    pub fn to_decimal128_bid(self: &Self) -> u128 {
+        return encode(self, &DECIMAL128, Encoding::Bid);
+    }
+
+    /// Encodes self as a decimal128 with a densely packed decimal
+    /// coefficient.
+    pub fn to_decimal128_dpd(self: &Self) -> u128 {
+        return encode(self, &DECIMAL128, Encoding::Dpd);
+    }
+
+    /// Decodes a decimal32 with a binary integer coefficient.
+    pub fn from_decimal32_bid(bits: u32) -> Result<BigNumber, MathErrors> {
+        return finite(decode(bits as u128, &DECIMAL32, Encoding::Bid));
+    }
+
+    /// Decodes a decimal32 with a densely packed decimal coefficient.
+    pub fn from_decimal32_dpd(bits: u32) -> Result<BigNumber, MathErrors> {
+        return finite(decode(bits as u128, &DECIMAL32, Encoding::Dpd));
+    }
+
+    /// Decodes a decimal64 with a binary integer coefficient.
+    pub fn from_decimal64_bid(bits: u64) -> Result<BigNumber, MathErrors> {
+        return finite(decode(bits as u128, &DECIMAL64, Encoding::Bid));
+    }
+
+    /// Decodes a decimal64 with a densely packed decimal coefficient.
+    pub fn from_decimal64_dpd(bits: u64) -> Result<BigNumber, MathErrors> {
+        return finite(decode(bits as u128, &DECIMAL64, Encoding::Dpd));
+    }
+
+    /// Decodes a decimal128 with a binary integer coefficient.
+    pub fn from_decimal128_bid(bits: u128) -> Result<BigNumber, MathErrors> {
+        return finite(decode(bits, &DECIMAL128, Encoding::Bid));
+    }
+
+    /// Decodes a decimal128 with a densely packed decimal coefficient.
+    pub fn from_decimal128_dpd(bits: u128) -> Result<BigNumber, MathErrors> {
+        return finite(decode(bits, &DECIMAL128, Encoding::Dpd));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_declets() {
+        for n in 0..1000 {
+            assert_eq!(decode_declet(encode_declet(n)), n);
+        }
+        let test_data = [
+            (0, 0x000),
+            (5, 0x005),
+            (80, 0x00A),
+            (750, 0x3D0),
+            (999, 0x0FF),
+        ];
+        for (n, bits) in test_data {
+            assert_eq!(encode_declet(n), bits);
+        }
+        // The redundant patterns for values made only of eights and nines.
+        assert_eq!(decode_declet(0x3FF), 999);
+        assert_eq!(decode_declet(0x36E), 888);
+    }
+
+    // Reads a mantissa with an optional exponent such as "9999999E90".
+    fn number(s: &str) -> BigNumber {
+        let (mantissa, exponent) = s.split_once('E').unwrap_or((s, "0"));
+        let exponent: isize = exponent.parse().unwrap();
+        return BigNumber::from_str(mantissa)
+            .unwrap()
+            .shift_decimal(exponent);
+    }
+
+    #[test]
+    fn test_decimal32() {
+        // (value, BID, DPD, value after rounding to the format)
+        let test_data = [
+            ("1", 0x32800001, 0x22500001, "1"),
+            ("-7.50", 0xB18002EE, 0xA23003D0, "-7.50"),
+            ("0.00", 0x31800000, 0x22300000, "0.00"),
+            ("9999999E90", 0x77F8967F, 0x77F3FCFF, "9999999E90"),
+            ("1234567.5", 0x3292D688, 0x2654D2E8, "1234568"),
+            ("1234568.5", 0x3292D688, 0x2654D2E8, "1234568"),
+            ("9999999.5", 0x330F4240, 0x26600000, "1000000E1"),
+            ("2.5", 0x32000019, 0x22400025, "2.5"),
+            ("1E-101", 0x00000001, 0x00000001, "1E-101"),
+            ("5E-102", 0x00000000, 0x00000000, "0E-101"),
+            ("6E-102", 0x00000001, 0x00000001, "1E-101"),
+            ("-1E-200", 0x80000000, 0x80000000, "0E-101"),
+            // 3.4999995E-101 rounds down, not to 3.500000E-101 and then up.
+            ("34999995E-108", 0x00000003, 0x00000003, "3E-101"),
+        ];
+        for (value, bid, dpd, rounded) in test_data {
+            let x = number(value);
+            let rounded = number(rounded);
+            assert_eq!(x.to_decimal32_bid(), bid);
+            assert_eq!(x.to_decimal32_dpd(), dpd);
+            let from_bid = BigNumber::from_decimal32_bid(bid).unwrap();
+            let from_dpd = BigNumber::from_decimal32_dpd(dpd).unwrap();
+            assert_eq!(from_bid.to_string(), rounded.to_string());
+            assert_eq!(from_dpd.to_string(), rounded.to_string());
+        }
+    }
+
+    #[test]
+    fn test_decimal64() {
+        // (value, BID, DPD, value after rounding to the format)
+        let test_data = [
+            ("1", 0x31C0000000000001, 0x2238000000000001, "1"),
+            ("-7.50", 0xB1800000000002EE, 0xA2300000000003D0, "-7.50"),
+            (
+                "9999999999999999E369",
+                0x77FB86F26FC0FFFF,
+                0x77FCFF3FCFF3FCFF,
+                "9999999999999999E369",
+            ),
+            (
+                "1E384",
+                0x5FE38D7EA4C68000,
+                0x47FC000000000000,
+                "1000000000000000E369",
+            ),
+            (
+                "1234567.5",
+                0x31A0000000BC614B,
+                0x2234000001271775,
+                "1234567.5",
+            ),
+            (
+                "123456789012345678901234567890.12345678",
+                0x338462D53C8ABAC1,
+                0x267134B9C1E28E57,
+                "1234567890123457E14",
+            ),
+            ("1E-398", 0x0000000000000001, 0x0000000000000001, "1E-398"),
+            (
+                "-0.000001",
+                0xB100000000000001,
+                0xA220000000000001,
+                "-0.000001",
+            ),
+        ];
+        for (value, bid, dpd, rounded) in test_data {
+            let x = number(value);
+            let rounded = number(rounded);
+            assert_eq!(x.to_decimal64_bid(), bid);
+            assert_eq!(x.to_decimal64_dpd(), dpd);
+            let from_bid = BigNumber::from_decimal64_bid(bid).unwrap();
+            let from_dpd = BigNumber::from_decimal64_dpd(dpd).unwrap();
+            assert_eq!(from_bid.to_string(), rounded.to_string());
+            assert_eq!(from_dpd.to_string(), rounded.to_string());
+        }
+    }
+
+    #[test]
+    fn test_decimal128() {
+        // (value, BID, DPD, value after rounding to the format)
+        let test_data = [
+            (
+                "1",
+                0x30400000000000000000000000000001,
+                0x22080000000000000000000000000001,
+                "1",
+            ),
+            (
+                "-7.50",
+                0xB03C00000000000000000000000002EE,
+                0xA20780000000000000000000000003D0,
+                "-7.50",
+            ),
+            (
+                "9999999999999999999999999999999999E6111",
+                0x5FFFED09BEAD87C0378D8E63FFFFFFFF,
+                0x77FFCFF3FCFF3FCFF3FCFF3FCFF3FCFF,
+                "9999999999999999999999999999999999E6111",
+            ),
+            (
+                "123456789012345678901234567890.12345678",
+                0x30383CDE6FFF9732DE825CD07E96AFF3,
+                0x2607134B9C1E28E56F3C127177823535,
+                "123456789012345678901234567890.1235",
+            ),
+            (
+                "12345678901234567890123456789012345",
+                0x30423CDE6FFF9732DE825CD07E96AFF2,
+                0x2608534B9C1E28E56F3C127177823534,
+                "1234567890123456789012345678901234E1",
+            ),
+            (
+                "1E-6176",
+                0x00000000000000000000000000000001,
+                0x00000000000000000000000000000001,
+                "1E-6176",
+            ),
+        ];
+        for (value, bid, dpd, rounded) in test_data {
+            let x = number(value);
+            let rounded = number(rounded);
+            assert_eq!(x.to_decimal128_bid(), bid);
+            assert_eq!(x.to_decimal128_dpd(), dpd);
+            let from_bid = BigNumber::from_decimal128_bid(bid).unwrap();
+            let from_dpd = BigNumber::from_decimal128_dpd(dpd).unwrap();
+            assert_eq!(from_bid.to_string(), rounded.to_string());
+            assert_eq!(from_dpd.to_string(), rounded.to_string());
+        }
+    }
+
+    #[test]
+    fn test_special_values() {
+        // Values too large for the format become infinities.
+        assert_eq!(number("1E97").to_decimal32_bid(), 0x78000000);
+        assert_eq!(number("-1E97").to_decimal32_dpd(), 0xF8000000);
+        assert_eq!(number("1E385").to_decimal64_bid(), 0x7800000000000000);
+        assert_eq!(
+            number("1E6145").to_decimal128_dpd(),
+            0x78000000000000000000000000000000
+        );
+
+        // (bits, value)
+        let test_data = [
+            (0x78000000, IeeeDecimal::Infinity),
+            (0xF8000000, IeeeDecimal::NegativeInfinity),
+            (0x7C000000, IeeeDecimal::NaN),
+            (0x7E000000, IeeeDecimal::NaN),
+            (0xFC000000, IeeeDecimal::NaN),
+        ];
+        for (bits, value) in test_data {
+            assert_eq!(IeeeDecimal::from_decimal32_bid(bits), value);
+            assert_eq!(IeeeDecimal::from_decimal32_dpd(bits), value);
+            assert_eq!(
+                BigNumber::from_decimal32_bid(bits),
+                Err(MathErrors::DomainError)
+            );
+            assert_eq!(
+                BigNumber::from_decimal32_dpd(bits),
+                Err(MathErrors::DomainError)
+            );
+        }
+        assert_eq!(
+            IeeeDecimal::from_decimal128_bid(0x7C000000000000000000000000000000),
+            IeeeDecimal::NaN
+        );
+        assert_eq!(
+            IeeeDecimal::from_decimal64_dpd(0xF800000000000000),
+            IeeeDecimal::NegativeInfinity
+        );
+        assert_eq!(
+            BigNumber::from_decimal128_bid(0x7C000000000000000000000000000000),
+            Err(MathErrors::DomainError)
+        );
+
+        // The special values are written back the same way.
+        let test_data = [
+            IeeeDecimal::NaN,
+            IeeeDecimal::Infinity,
+            IeeeDecimal::NegativeInfinity,
+            IeeeDecimal::from(number("-1.50")),
+        ];
+        for value in test_data {
+            let bits = value.to_decimal32_bid();
+            assert_eq!(IeeeDecimal::from_decimal32_bid(bits), value);
+            let bits = value.to_decimal64_dpd();
+            assert_eq!(IeeeDecimal::from_decimal64_dpd(bits), value);
+            let bits = value.to_decimal128_bid();
+            assert_eq!(IeeeDecimal::from_decimal128_bid(bits), value);
+        }
+        assert_eq!(IeeeDecimal::NaN.to_decimal32_dpd(), 0x7C000000);
+        assert_eq!(IeeeDecimal::NegativeInfinity.to_decimal32_bid(), 0xF8000000);
+
+        // A BID128 coefficient past 10^34 is not canonical and reads as zero.
+        let x = BigNumber::from_decimal128_bid(0x6000FFFFFFFFFFFFFFFFFFFFFFFFFFFF).unwrap();
+        assert!(x.is_zero());
+        // So does a BID32 coefficient past 9999999.
+        let x = BigNumber::from_decimal32_bid(0x6CBFFFFF).unwrap();
+        assert!(x.is_zero());
+    }
+}
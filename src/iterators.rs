@@ -3,9 +3,14 @@
 // External imports
 use std::cmp::max;
 use std::cmp::min;
+use std::cmp::Ordering;
 
 // Inter crate imports
+use super::exponential::GUARD_DIGITS;
+use super::magnitude;
+use super::BigNumber;
 use super::Digits;
+use super::MathErrors;
 
 #[derive(Debug, Default)]
 enum Smaller {
@@ -333,9 +338,156 @@ impl<'a> Iterator for DecimalsByDescendingPower<'a> {
     }
 }
 
+/// Yields the decimal digits of a constant one at a time without end.
+///
+/// Digits are taken in blocks from the cached expansions behind
+/// [BigNumber::pi] and [BigNumber::e]. Each block doubles the number of
+/// digits held, so n digits cost about as much as computing the constant to
+/// n digits once.
+pub struct ConstantDigits {
+    digits: Vec<Digits>,
+    yielded: usize,
+    constant: fn(usize) -> BigNumber,
+}
+
+impl ConstantDigits {
+    fn new(constant: fn(usize) -> BigNumber) -> ConstantDigits {
+        return ConstantDigits {
+            digits: Vec::new(),
+            yielded: 0,
+            constant,
+        };
+    }
+
+    /// The digits of π starting with 3.
+    pub fn pi() -> ConstantDigits {
+        return ConstantDigits::new(BigNumber::pi);
+    }
+
+    /// The digits of e starting with 2.
+    pub fn e() -> ConstantDigits {
+        return ConstantDigits::new(BigNumber::e);
+    }
+
+    /// Replaces the digits held with at least twice as many.
+    fn extend(&mut self) {
+        let mut length: usize = max(2 * self.digits.len(), 32);
+        loop {
+            let (coefficient, _) = (self.constant)(length + GUARD_DIGITS).coefficient();
+            let mut coefficient: Vec<Digits> = magnitude::significant(&coefficient).to_vec();
+            coefficient.resize(length + GUARD_DIGITS, Digits::Zero);
+            // The rounded expansion is within one unit of its last digit, so
+            // the leading digits are exact unless the guard digits are all
+            // zeros or all nines.
+            let guard: &[Digits] = &coefficient[length..];
+            if guard.iter().all(|d| *d == Digits::Zero) || guard.iter().all(|d| *d == Digits::Nine)
+            {
+                length += GUARD_DIGITS;
+                continue;
+            }
+            coefficient.truncate(length);
+            self.digits = coefficient;
+            return;
+        }
+    }
+}
+
+impl Iterator for ConstantDigits {
+    type Item = Digits;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.yielded == self.digits.len() {
+            self.extend();
+        }
+        self.yielded += 1;
+        return Some(self.digits[self.yielded - 1]);
+    }
+}
+
+/// Yields the decimal digits of the square root of a BigNumber one at a time
+/// without end.
+///
+/// The digits of the integer portion come first, followed by the digits of
+/// the decimal portion.
+pub struct SquareRootDigits {
+    // Pairs of digits from the radicand, most significant first. Once these
+    // run out every further pair is zero.
+    pairs: std::vec::IntoIter<(Digits, Digits)>,
+    root: Vec<Digits>,
+    remainder: Vec<Digits>,
+    integer_length: usize,
+}
+
+impl SquareRootDigits {
+    /// [MathErrors::DomainError] is returned if x is negative.
+    pub fn new(x: &BigNumber) -> Result<SquareRootDigits, MathErrors> {
+        if x.is_negative() && !x.is_zero() {
+            return Err(MathErrors::DomainError);
+        }
+        let mut integer: Vec<Digits> = magnitude::significant(&x.integer).to_vec();
+        if integer.len() % 2 == 1 {
+            integer.insert(0, Digits::Zero);
+        }
+        if integer.is_empty() {
+            integer = vec![Digits::Zero, Digits::Zero];
+        }
+        let mut decimal: Vec<Digits> = x.decimal.clone();
+        if decimal.len() % 2 == 1 {
+            decimal.push(Digits::Zero);
+        }
+        let pairs: Vec<(Digits, Digits)> = integer
+            .chunks(2)
+            .chain(decimal.chunks(2))
+            .map(|pair| (pair[0], pair[1]))
+            .collect();
+        return Ok(SquareRootDigits {
+            pairs: pairs.into_iter(),
+            root: Vec::new(),
+            remainder: Vec::new(),
+            integer_length: integer.len() / 2,
+        });
+    }
+
+    /// Returns how many of the yielded digits belong to the integer portion.
+    pub fn integer_length(&self) -> usize {
+        return self.integer_length;
+    }
+}
+
+impl Iterator for SquareRootDigits {
+    type Item = Digits;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (high, low) = self.pairs.next().unwrap_or_default();
+        self.remainder = magnitude::shift_left(&self.remainder, 2);
+        self.remainder = magnitude::add(&self.remainder, &[high, low]);
+        // Find the largest digit d where (20 root + d) d fits in the
+        // remainder.
+        let twenty_root: Vec<Digits> = magnitude::multiply_small(&self.root, 20);
+        let mut digit: u32 = 9;
+        let mut product: Vec<Digits> = magnitude::multiply_small(
+            &magnitude::add(&twenty_root, &magnitude::from_u128(digit as u128)),
+            digit,
+        );
+        while magnitude::compare(&product, &self.remainder) == Ordering::Greater {
+            digit -= 1;
+            product = magnitude::multiply_small(
+                &magnitude::add(&twenty_root, &magnitude::from_u128(digit as u128)),
+                digit,
+            );
+        }
+        self.remainder = magnitude::subtract(&self.remainder, &product);
+        let result: Digits = magnitude::digit(digit as usize);
+        self.root.push(result);
+        return Some(result);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::str::FromStr;
+
     #[test]
     fn test_decimals_ascending() {
         let test_data = [
@@ -419,4 +571,42 @@ mod test {
             }
         }
     }
+
+    fn collect_digits(digits: impl Iterator<Item = Digits>, count: usize) -> String {
+        return digits.take(count).map(|d| d.to_char()).collect();
+    }
+
+    #[test]
+    fn test_constant_digits() {
+        let test_data = [
+            (ConstantDigits::pi(), "31415926535897932384626433832795"),
+            (ConstantDigits::e(), "27182818284590452353602874713526"),
+        ];
+        for (digits, expected) in test_data {
+            assert_eq!(collect_digits(digits, expected.len()), expected);
+        }
+        // Digits keep coming across the blocks taken from the cache.
+        let pi: String = BigNumber::pi(310).to_string().replace('.', "");
+        assert_eq!(collect_digits(ConstantDigits::pi(), 300), pi[..300]);
+        let e: String = BigNumber::e(1010).to_string().replace('.', "");
+        assert_eq!(collect_digits(ConstantDigits::e(), 1000), e[..1000]);
+    }
+
+    #[test]
+    fn test_square_root_digits() {
+        let test_data = [
+            ("2", 1, "14142135623730950488"),
+            ("0", 1, "00000"),
+            ("0.04", 1, "02000"),
+            ("1234.5", 2, "3513545"),
+            ("0.001", 1, "0031622776"),
+            ("144", 2, "12000"),
+        ];
+        for (x, integer_length, expected) in test_data {
+            let digits = SquareRootDigits::new(&BigNumber::from_str(x).unwrap()).unwrap();
+            assert_eq!(digits.integer_length(), integer_length);
+            assert_eq!(collect_digits(digits, expected.len()), expected);
+        }
+        assert!(SquareRootDigits::new(&BigNumber::from_str("-1").unwrap()).is_err());
+    }
 }
@@ -15,31 +15,97 @@ use std::ops::Sub;
 use std::str::FromStr;
 
 // Internal module declarations and imports.
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "bson")]
+mod bson;
+mod cbor;
+mod constants;
 mod digits;
+mod error_function;
+mod exponential;
+mod gamma;
+mod hyperbolic;
+mod ieee754;
 mod iterators;
-use digits::Digits;
+mod magnitude;
+mod mysql;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+mod number_theory;
+mod operators;
+mod postgres;
+mod primes;
+mod primitives;
+mod range;
+mod remainder;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod shift;
+mod unscaled;
+#[cfg(feature = "arrow")]
+pub use arrow::OnOverflow;
+pub use digits::Digits;
+pub use ieee754::IeeeDecimal;
+pub use iterators::ConstantDigits;
 use iterators::DecimalsByAscendingPower;
 use iterators::DecimalsByDescendingPower;
 use iterators::IntegersByAscendingPower;
+pub use iterators::SquareRootDigits;
+pub use postgres::PgNumeric;
+pub use range::DecimalRange;
+
+/// Significant digits kept by the `/` operator, which has no way to be told
+/// how many digits a quotient that does not terminate should have.
+pub const DIVISION_PRECISION: usize = 34;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum MathErrors {
     DivisionByZero,
+    DomainError,
+    InvalidDigit,
+    Overflow,
     ParseError,
+    PrecisionLoss,
+    UnsupportedRadix,
 }
 
+impl Display for MathErrors {
+    fn fmt(self: &Self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let message: &str = match self {
+            MathErrors::DivisionByZero => "division by zero",
+            MathErrors::DomainError => "argument outside the domain of the operation",
+            MathErrors::InvalidDigit => "invalid decimal digit",
+            MathErrors::Overflow => "value out of range for the target",
+            MathErrors::ParseError => "invalid decimal number",
+            MathErrors::PrecisionLoss => "value has more digits than the target can hold",
+            MathErrors::UnsupportedRadix => "only radix 10 is supported",
+        };
+        return formatter.write_str(message);
+    }
+}
+
+impl std::error::Error for MathErrors {}
+
+/// The sign of a BigNumber. Zero is always positive.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
-enum Sign {
+pub enum Sign {
     Negative,
     #[default]
     Positive,
 }
 
 impl Sign {
-    fn is_negative(self) -> bool {
+    pub fn is_negative(self) -> bool {
         return self == Sign::Negative;
     }
-    fn is_positive(self) -> bool {
+    pub fn is_positive(self) -> bool {
         return self == Sign::Positive;
     }
 }
@@ -124,50 +190,304 @@ impl BigNumber {
     }
 
     /// Cleans up the internal representation of a BigNumber.
+    ///
+    /// Leading zeros are removed from the integer portion, trailing zeros are
+    /// removed from the decimal portion and negative zero becomes zero.
     pub fn normalize(self: &mut Self) {
-        todo!();
+        magnitude::trim(&mut self.integer);
+        if self.integer.is_empty() {
+            self.integer.push(Digits::Zero);
+        }
+        while self.decimal.last() == Some(&Digits::Zero) {
+            self.decimal.pop();
+        }
+        if self.is_zero() {
+            self.sign = Sign::Positive;
+        }
     }
 
-    /// this performs Nines Complement addition of the subtrahend and the
-    /// minuend.
-    fn sub_helper(subtrahend: &BigNumber, minuend: &BigNumber) -> BigNumber {
-        let length: usize = cmp::max(subtrahend.decimal.len(), minuend.decimal.len());
-        let mut results_decimal: Vec<Digits> = Vec::with_capacity(length);
-        let mut carry: Digits = Digits::Zero;
-        let mut temp: Digits;
+    /// Returns true if every digit of self is zero.
+    pub fn is_zero(self: &Self) -> bool {
+        return magnitude::is_zero(&self.integer) && magnitude::is_zero(&self.decimal);
+    }
 
-        let da: DecimalsByAscendingPower =
-            DecimalsByAscendingPower::new(&subtrahend.decimal, &minuend.decimal);
-        for (x, y) in da {
-            (temp, carry) = Digits::complement(x).fused_addition(y, carry);
-            results_decimal.push(temp);
+    /// Returns true if self has no nonzero decimal digits.
+    pub fn is_integer(self: &Self) -> bool {
+        return magnitude::is_zero(&self.decimal);
+    }
+
+    /// Returns the number of digits in the decimal portion, including any
+    /// trailing zeros.
+    pub fn scale(self: &Self) -> usize {
+        return self.decimal.len();
+    }
+
+    /// Returns the number of significant digits, counting from the first
+    /// nonzero digit through the last stored decimal digit.
+    ///
+    /// Zero has a precision of one.
+    pub fn precision(self: &Self) -> usize {
+        let (coefficient, _) = self.coefficient();
+        return cmp::max(magnitude::significant(&coefficient).len(), 1);
+    }
+
+    /// Returns the integer portion of self, truncated toward zero.
+    pub fn integer_part(self: &Self) -> BigNumber {
+        return BigNumber::from_coefficient(self.sign, self.integer.clone(), 0);
+    }
+
+    /// Returns the decimal portion of self, which has the sign of self.
+    pub fn fractional_part(self: &Self) -> BigNumber {
+        return BigNumber::from_coefficient(self.sign, self.decimal.clone(), self.decimal.len());
+    }
+
+    /// Returns the digits of the integer portion, most significant first.
+    ///
+    /// Leading zeros are skipped, so an integer portion of zero yields a
+    /// single [Digits::Zero].
+    pub fn integer_digits(
+        self: &Self,
+    ) -> impl DoubleEndedIterator<Item = Digits> + ExactSizeIterator + '_ {
+        let mut digits: &[Digits] = magnitude::significant(&self.integer);
+        if digits.is_empty() {
+            digits = &[Digits::Zero];
         }
-        results_decimal.reverse();
-        for x in results_decimal.iter_mut() {
-            *x = Digits::complement(*x);
+        return digits.iter().copied();
+    }
+
+    /// Returns the digits of the decimal portion, most significant first,
+    /// including any trailing zeros.
+    pub fn fraction_digits(
+        self: &Self,
+    ) -> impl DoubleEndedIterator<Item = Digits> + ExactSizeIterator + '_ {
+        return self.decimal.iter().copied();
+    }
+
+    /// Builds a BigNumber from its sign and the digits of its integer and
+    /// decimal portions, each most significant first.
+    ///
+    /// Leading zeros of the integer portion are dropped and zero is always
+    /// positive.
+    pub fn from_digits<I, F>(sign: Sign, integer: I, fraction: F) -> BigNumber
+    where
+        I: IntoIterator<Item = Digits>,
+        F: IntoIterator<Item = Digits>,
+    {
+        let mut coefficient: Vec<Digits> = integer.into_iter().collect();
+        let length: usize = coefficient.len();
+        coefficient.extend(fraction);
+        let scale: usize = coefficient.len() - length;
+        return BigNumber::from_coefficient(sign, coefficient, scale);
+    }
+
+    /// Returns -1, 0 or 1 according to the sign of self.
+    pub fn signum(self: &Self) -> BigNumber {
+        if self.is_zero() {
+            return BigNumber::from_integer(0);
+        }
+        if self.is_negative() {
+            return BigNumber::from_integer(-1);
+        }
+        return BigNumber::one();
+    }
+
+    /// Builds a BigNumber from the digits of its coefficient and the number of
+    /// those digits that belong to the decimal portion.
+    ///
+    /// Leading zeros are removed from the integer portion but the decimal
+    /// portion always has exactly scale digits.
+    fn from_coefficient(sign: Sign, coefficient: Vec<Digits>, scale: usize) -> BigNumber {
+        let mut integer: Vec<Digits> = coefficient;
+        if integer.len() <= scale {
+            integer.splice(0..0, vec![Digits::Zero; scale + 1 - integer.len()]);
+        }
+        let decimal: Vec<Digits> = integer.split_off(integer.len() - scale);
+        let mut result = BigNumber {
+            integer,
+            decimal,
+            sign,
+        };
+        magnitude::trim(&mut result.integer);
+        if result.integer.is_empty() {
+            result.integer.push(Digits::Zero);
+        }
+        if result.is_zero() {
+            result.sign = Sign::Positive;
         }
+        return result;
+    }
 
-        let length: usize = cmp::max(subtrahend.integer.len(), minuend.integer.len());
-        let mut results_integer: Vec<Digits> = Vec::with_capacity(length);
+    /// Returns the digits of self as a single integer and the number of those
+    /// digits that belong to the decimal portion.
+    fn coefficient(self: &Self) -> (Vec<Digits>, usize) {
+        let mut coefficient: Vec<Digits> =
+            Vec::with_capacity(self.integer.len() + self.decimal.len());
+        coefficient.extend(self.integer.iter());
+        coefficient.extend(self.decimal.iter());
+        return (coefficient, self.decimal.len());
+    }
+
+    /// Returns the coefficients of x and y padded to a common scale.
+    fn aligned_coefficients(x: &BigNumber, y: &BigNumber) -> (Vec<Digits>, Vec<Digits>, usize) {
+        let scale: usize = cmp::max(x.decimal.len(), y.decimal.len());
+        let (mut x_coefficient, x_scale) = x.coefficient();
+        let (mut y_coefficient, y_scale) = y.coefficient();
+        x_coefficient.resize(x_coefficient.len() + scale - x_scale, Digits::Zero);
+        y_coefficient.resize(y_coefficient.len() + scale - y_scale, Digits::Zero);
+        return (x_coefficient, y_coefficient, scale);
+    }
+
+    /// Returns the power of ten of the most significant nonzero digit.
+    ///
+    /// Zero is reported as having an exponent of zero.
+    pub fn adjusted_exponent(self: &Self) -> isize {
+        let (coefficient, scale) = self.coefficient();
+        let length: usize = magnitude::significant(&coefficient).len();
         if length == 0 {
-            results_integer.push(Digits::complement(carry));
+            return 0;
+        }
+        return length as isize - 1 - scale as isize;
+    }
+
+    fn one() -> BigNumber {
+        return BigNumber::from_coefficient(Sign::Positive, vec![Digits::One], 0);
+    }
+
+    fn half() -> BigNumber {
+        return BigNumber::from_coefficient(Sign::Positive, vec![Digits::Five], 1);
+    }
+
+    /// Returns a BigNumber equal to the integer x.
+    fn from_integer(x: i128) -> BigNumber {
+        let sign: Sign = if x < 0 {
+            Sign::Negative
         } else {
-            let ia: IntegersByAscendingPower =
-                IntegersByAscendingPower::new(&subtrahend.integer, &minuend.decimal);
-            for (x, y) in ia {
-                (temp, carry) = Digits::complement(x).fused_addition(y, carry);
-                results_integer.push(temp);
+            Sign::Positive
+        };
+        return BigNumber::from_coefficient(sign, magnitude::from_u128(x.unsigned_abs()), 0);
+    }
+
+    /// Returns the absolute value of self.
+    pub fn abs(self: &Self) -> BigNumber {
+        let mut result: BigNumber = self.clone();
+        result.sign = Sign::Positive;
+        return result;
+    }
+
+    fn negated(self: &Self) -> BigNumber {
+        let mut result: BigNumber = self.clone();
+        if !result.is_zero() {
+            result.negate();
+        }
+        return result;
+    }
+
+    /// Returns the exact sum of self and rhs.
+    fn sum(self: &Self, rhs: &Self) -> BigNumber {
+        let (x, y, scale) = BigNumber::aligned_coefficients(self, rhs);
+        if self.is_the_same_sign_as(rhs) {
+            return BigNumber::from_coefficient(self.sign, magnitude::add(&x, &y), scale);
+        }
+        match magnitude::compare(&x, &y) {
+            Ordering::Equal => {
+                return BigNumber::from_coefficient(Sign::Positive, Vec::new(), scale)
+            }
+            Ordering::Greater => {
+                return BigNumber::from_coefficient(self.sign, magnitude::subtract(&x, &y), scale);
             }
-            results_integer.reverse();
-            for x in results_integer.iter_mut() {
-                *x = Digits::complement(*x);
+            Ordering::Less => {
+                return BigNumber::from_coefficient(rhs.sign, magnitude::subtract(&y, &x), scale);
             }
         }
-        return BigNumber {
-            integer: results_integer,
-            decimal: results_decimal,
-            sign: Sign::Positive,
+    }
+
+    /// Returns the exact difference of self and rhs.
+    fn difference(self: &Self, rhs: &Self) -> BigNumber {
+        return self.sum(&rhs.negated());
+    }
+
+    /// Returns the exact product of self and rhs.
+    fn product(self: &Self, rhs: &Self) -> BigNumber {
+        let (x, x_scale) = self.coefficient();
+        let (y, y_scale) = rhs.coefficient();
+        let sign: Sign = if self.is_the_same_sign_as(rhs) {
+            Sign::Positive
+        } else {
+            Sign::Negative
+        };
+        return BigNumber::from_coefficient(sign, magnitude::multiply(&x, &y), x_scale + y_scale);
+    }
+
+    /// Divides self by rhs rounding the result half to even at scale decimal
+    /// places.
+    fn quotient(self: &Self, rhs: &Self, scale: usize) -> Result<BigNumber, MathErrors> {
+        let (x, x_scale) = self.coefficient();
+        let (y, y_scale) = rhs.coefficient();
+        let numerator: Vec<Digits> = magnitude::shift_left(&x, y_scale + scale);
+        let denominator: Vec<Digits> = magnitude::shift_left(&y, x_scale);
+        let (mut result, remainder) = magnitude::divide(&numerator, &denominator)?;
+        let twice_remainder: Vec<Digits> = magnitude::multiply_small(&remainder, 2);
+        let round_up: bool = match magnitude::compare(&twice_remainder, &denominator) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => result.last().is_some_and(|d| d.to_usize() % 2 == 1),
+        };
+        if round_up {
+            result = magnitude::add(&result, &[Digits::One]);
+        }
+        let sign: Sign = if self.is_the_same_sign_as(rhs) {
+            Sign::Positive
+        } else {
+            Sign::Negative
         };
+        return Ok(BigNumber::from_coefficient(sign, result, scale));
+    }
+
+    /// Divides self by rhs rounding the result to precision significant
+    /// digits.
+    fn quotient_to_precision(
+        self: &Self,
+        rhs: &Self,
+        precision: usize,
+    ) -> Result<BigNumber, MathErrors> {
+        let exponent: isize = self.adjusted_exponent() - rhs.adjusted_exponent();
+        let scale: usize = cmp::max(0, precision as isize - exponent + 1) as usize;
+        return Ok(self.quotient(rhs, scale)?.round_to_precision(precision));
+    }
+
+    /// Rounds self half to even at scale decimal places.
+    fn round_to_scale(self: &Self, scale: usize) -> BigNumber {
+        if self.decimal.len() <= scale {
+            return self.clone();
+        }
+        let (coefficient, current_scale) = self.coefficient();
+        let rounded: Vec<Digits> = magnitude::round_half_even(&coefficient, current_scale - scale);
+        return BigNumber::from_coefficient(self.sign, rounded, scale);
+    }
+
+    /// Rounds self half to even to precision significant digits.
+    fn round_to_precision(self: &Self, precision: usize) -> BigNumber {
+        let (coefficient, scale) = self.coefficient();
+        let length: usize = magnitude::significant(&coefficient).len();
+        if length <= precision {
+            return self.clone();
+        }
+        let mut count: usize = length - precision;
+        let mut rounded: Vec<Digits> = magnitude::round_half_even(&coefficient, count);
+        // Rounding up to a power of ten carries into a new digit, so one more
+        // trailing zero is dropped to keep precision digits.
+        if magnitude::significant(&rounded).len() > precision {
+            rounded.pop();
+            count += 1;
+        }
+        if count <= scale {
+            return BigNumber::from_coefficient(self.sign, rounded, scale - count);
+        }
+        return BigNumber::from_coefficient(
+            self.sign,
+            magnitude::shift_left(&rounded, count - scale),
+            0,
+        );
     }
 
     /// Returns a BigNumber that represents zero.
@@ -193,22 +513,17 @@ impl Clone for BigNumber {
     }
 }
 
+impl FromIterator<Digits> for BigNumber {
+    /// Builds a non-negative integer from digits given most significant
+    /// first.
+    fn from_iter<T: IntoIterator<Item = Digits>>(iter: T) -> Self {
+        return BigNumber::from_digits(Sign::Positive, iter, Vec::new());
+    }
+}
+
 impl From<u128> for BigNumber {
     fn from(input: u128) -> Self {
-        let mut x = input;
-        let mut jim: Vec<Digits> = Vec::with_capacity(16);
-        while x > 10 {
-            let t = x % 10;
-            jim.push(Digits::from(t));
-            x /= 10;
-        }
-        jim.push(Digits::from(x));
-        jim.reverse();
-        return BigNumber {
-            integer: jim,
-            decimal: Vec::new(),
-            sign: Sign::Positive,
-        };
+        return BigNumber::from_coefficient(Sign::Positive, magnitude::from_u128(input), 0);
     }
 }
 
@@ -238,26 +553,26 @@ impl FromStr for BigNumber {
             None => {
                 if result_sign.is_negative() {
                     for character in s[1..].chars() {
-                        result_integer.push(Digits::from(character));
+                        result_integer.push(Digits::try_from(character)?);
                     }
                 } else {
                     for character in s.chars() {
-                        result_integer.push(Digits::from(character));
+                        result_integer.push(Digits::try_from(character)?);
                     }
                 }
             }
             Some(periods_location) => {
                 if result_sign.is_negative() {
                     for character in s[1..periods_location].chars() {
-                        result_integer.push(Digits::from(character));
+                        result_integer.push(Digits::try_from(character)?);
                     }
                 } else {
                     for character in s[0..periods_location].chars() {
-                        result_integer.push(Digits::from(character));
+                        result_integer.push(Digits::try_from(character)?);
                     }
                 }
                 for character in s[(periods_location + 1)..].chars() {
-                    result_decimal.push(Digits::from(character));
+                    result_decimal.push(Digits::try_from(character)?);
                 }
             }
         }
@@ -270,96 +585,59 @@ impl FromStr for BigNumber {
     }
 }
 
-impl Add for BigNumber {
-    type Output = Self;
+impl Add<&BigNumber> for &BigNumber {
+    type Output = BigNumber;
 
     /// Adds rhs to self.
     ///
-    /// Call [`BigNumber::normalize`] on both values first.
-    ///
     /// Rule 1: If self and rhs are both of the same sign then add rhs to self.
     /// The result has the same sign as both self and rhs.
     ///
     /// Rule 2: If self and rhs are not of the same sign the subtract the
     /// smaller magnitude number from the larger magnitude number. The result
     /// has the sign of the largest magnitude number.
+    fn add(self: Self, rhs: &BigNumber) -> BigNumber {
+        if self.is_the_same_sign_as(rhs) {
+            return BigNumber::add_helper(self, rhs);
+        }
+        return self.sum(rhs);
+    }
+}
+
+impl Add for BigNumber {
+    type Output = Self;
+
+    /// Adds rhs to self. See the implementation for references.
     fn add(self: Self, rhs: Self) -> Self {
-        let lhs: &BigNumber = &self;
+        return &self + &rhs;
+    }
+}
 
-        if lhs.is_the_same_sign_as(&rhs) {
-            return BigNumber::add_helper(lhs, &rhs);
-        } else {
-            match lhs.cmp(&rhs) {
-                Ordering::Equal => {
-                    return BigNumber::zero();
-                }
-                Ordering::Greater => {
-                    let mut result: BigNumber = BigNumber::sub_helper(lhs, &rhs);
-                    result.sign = lhs.sign;
-                    return result;
-                }
-                Ordering::Less => {
-                    let mut result: BigNumber = BigNumber::sub_helper(&rhs, lhs);
-                    result.sign = rhs.sign;
-                    return result;
-                }
-            }
-        }
+impl Sub<&BigNumber> for &BigNumber {
+    type Output = BigNumber;
+
+    /// Subtracts rhs from self.
+    fn sub(self: Self, rhs: &BigNumber) -> BigNumber {
+        return self.difference(rhs);
     }
 }
 
 impl Sub for BigNumber {
     type Output = Self;
 
-    /// Subtracts rhs from self.
-    ///
-    /// Call [`BigNumber::normalize`] on both values first.
-    ///
-    /// Rule 1: If self and rhs are both positive then subtract rhs from self.
-    /// The answer is negative if rhs is greater than self.
-    ///
-    /// Rule 2: If self is negative and rhs is positive then add rhs to self.
-    /// The answer is negative.
-    ///
-    /// Rule 3: If self is negative and rhs is negative then subtract the
-    /// smaller magnitude number from the larger magnitude number. The answer
-    /// is negative if self is larger. The answer is positive is rhs is larger.
+    /// Subtracts rhs from self. See the implementation for references.
     fn sub(self: Self, rhs: Self) -> Self {
-        let lhs: &BigNumber = &self;
-
-        let order: Ordering = lhs.cmp(&rhs);
-        // Subtracting equal quantities from each other is defined to be zero.
-        if order == Ordering::Equal {
-            return BigNumber::zero();
-        }
-        let mut result: BigNumber = BigNumber::zero();
-        if lhs.is_positive() && rhs.is_positive() {
-            result = BigNumber::sub_helper(lhs, &rhs);
-            if order == Ordering::Less {
-                result.sign = Sign::Negative;
-            }
-        }
-        if lhs.is_negative() && rhs.is_positive() {
-            result = BigNumber::add_helper(lhs, &rhs);
-            result.sign = Sign::Negative;
-        }
-        if lhs.is_negative() && rhs.is_negative() {
-            if order == Ordering::Greater {
-                result = BigNumber::sub_helper(lhs, &rhs);
-                result.sign = Sign::Negative;
-            }
-            if order == Ordering::Less {
-                result = BigNumber::sub_helper(&rhs, lhs);
-                result.sign = Sign::Positive;
-            }
-        }
-        return result;
+        return &self - &rhs;
     }
 }
 
 impl Ord for BigNumber {
     fn cmp(&self, rhs: &Self) -> Ordering {
         let lhs = &self;
+        // Zero is equal to zero whatever sign it carries.
+        if lhs.is_zero() && rhs.is_zero() {
+            return Ordering::Equal;
+        }
         // Negative numbers are always less than positive numbers.
         if lhs.is_negative() && rhs.is_positive() {
             return Ordering::Less;
@@ -368,47 +646,34 @@ impl Ord for BigNumber {
             return Ordering::Greater;
         }
         // We now know that lhs and rhs have the same sign.
-        // Whichever side has more integer digits is larger.
-        if lhs.integer.len() > rhs.integer.len() {
-            return Ordering::Greater;
-        }
-        if rhs.integer.len() > rhs.integer.len() {
-            return Ordering::Less;
+        // Whichever side has more significant integer digits is larger.
+        let lhs_integer: &[Digits] = magnitude::significant(&lhs.integer);
+        let rhs_integer: &[Digits] = magnitude::significant(&rhs.integer);
+        let mut order: Ordering = lhs_integer.len().cmp(&rhs_integer.len());
+        if order == Ordering::Equal {
+            // We now know that lhs and rhs have the same number of integer
+            // digits.
+            order = lhs_integer.cmp(rhs_integer);
         }
-        // We now know that lhs and rhs have the same number of integer digits.
-        let x = lhs.integer.cmp(&rhs.integer);
-        match x {
-            Ordering::Greater => return Ordering::Greater,
-            Ordering::Less => return Ordering::Less,
-            Ordering::Equal => {
-                let mut order: Ordering = Ordering::Equal;
-                let mut dd: DecimalsByDescendingPower =
-                    DecimalsByDescendingPower::new(&lhs.decimal, &rhs.decimal);
-                while order == Ordering::Equal {
-                    let next = dd.next();
-                    if next.is_none() {
-                        break;
-                    } else {
-                        let (x, y) = next.unwrap();
-                        order = x.cmp(&y);
-                    }
-                }
-                if lhs.is_positive() {
-                    match order {
-                        Ordering::Equal => return Ordering::Equal,
-                        Ordering::Greater => return Ordering::Greater,
-                        Ordering::Less => return Ordering::Less,
-                    };
+        if order == Ordering::Equal {
+            let mut dd: DecimalsByDescendingPower =
+                DecimalsByDescendingPower::new(&lhs.decimal, &rhs.decimal);
+            while order == Ordering::Equal {
+                let next = dd.next();
+                if next.is_none() {
+                    break;
                 } else {
-                    // Negative numbers
-                    match order {
-                        Ordering::Equal => return Ordering::Equal,
-                        Ordering::Greater => return Ordering::Less,
-                        Ordering::Less => return Ordering::Greater,
-                    }
+                    let (x, y) = next.unwrap();
+                    order = x.cmp(&y);
                 }
             }
         }
+        if lhs.is_positive() {
+            return order;
+        } else {
+            // Larger magnitudes are smaller negative numbers.
+            return order.reverse();
+        }
     }
 }
 
@@ -564,6 +829,51 @@ mod test {
                 ),
                 Ordering::Less,
             ),
+            (
+                (
+                    BigNumber {
+                        integer: vec![Digits::Three],
+                        decimal: Vec::new(),
+                        sign: Sign::Negative,
+                    },
+                    BigNumber {
+                        integer: vec![Digits::Three, Digits::Zero],
+                        decimal: Vec::new(),
+                        sign: Sign::Negative,
+                    },
+                ),
+                Ordering::Greater,
+            ),
+            (
+                (
+                    BigNumber {
+                        integer: vec![Digits::Zero, Digits::Five],
+                        decimal: vec![Digits::Five],
+                        sign: Sign::Positive,
+                    },
+                    BigNumber {
+                        integer: vec![Digits::One, Digits::Zero],
+                        decimal: Vec::new(),
+                        sign: Sign::Positive,
+                    },
+                ),
+                Ordering::Less,
+            ),
+            (
+                (
+                    BigNumber {
+                        integer: vec![Digits::Zero],
+                        decimal: Vec::new(),
+                        sign: Sign::Negative,
+                    },
+                    BigNumber {
+                        integer: Vec::new(),
+                        decimal: vec![Digits::Zero],
+                        sign: Sign::Positive,
+                    },
+                ),
+                Ordering::Equal,
+            ),
         ];
         for ((left, right), expected) in test_data {
             assert_eq!(left.cmp(&right), expected);
@@ -696,4 +1006,91 @@ mod test {
             assert_eq!(example, expected);
         }
     }
+
+    #[test]
+    fn test_big_number_accessors() {
+        // (value, scale, precision, adjusted exponent, is integer)
+        let test_data = [
+            ("123.4500", 4, 7, 2, false),
+            ("-0.00123", 5, 3, -3, false),
+            ("7.0", 1, 2, 0, true),
+            ("-11", 0, 2, 1, true),
+            ("0.000", 3, 1, 0, true),
+        ];
+        for (x, scale, precision, exponent, integer) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.scale(), scale);
+            assert_eq!(x.precision(), precision);
+            assert_eq!(x.adjusted_exponent(), exponent);
+            assert_eq!(x.is_integer(), integer);
+        }
+    }
+
+    #[test]
+    fn test_big_number_parts() {
+        // (value, integer part, fractional part, signum, abs)
+        let test_data = [
+            ("123.45", "123", "0.45", "1", "123.45"),
+            ("-7.5", "-7", "-0.5", "-1", "7.5"),
+            ("-0.25", "0", "-0.25", "-1", "0.25"),
+            ("42", "42", "0", "1", "42"),
+            ("0.0", "0", "0", "0", "0"),
+        ];
+        for (x, integer, fraction, signum, abs) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.integer_part(), BigNumber::from_str(integer).unwrap());
+            assert_eq!(x.fractional_part(), BigNumber::from_str(fraction).unwrap());
+            assert_eq!(x.signum(), BigNumber::from_str(signum).unwrap());
+            assert_eq!(x.abs(), BigNumber::from_str(abs).unwrap());
+            assert_eq!(x.is_zero(), signum == "0");
+        }
+        assert!(BigNumber::from_str("-7.5")
+            .unwrap()
+            .integer_part()
+            .is_negative());
+        assert!(!BigNumber::from_str("-0.5")
+            .unwrap()
+            .integer_part()
+            .is_negative());
+    }
+
+    #[test]
+    fn test_big_number_digits() {
+        let x = BigNumber::from_str("-0012.340").unwrap();
+        let integer: Vec<Digits> = x.integer_digits().collect();
+        let fraction: Vec<Digits> = x.fraction_digits().collect();
+        assert_eq!(integer, vec![Digits::One, Digits::Two]);
+        assert_eq!(fraction, vec![Digits::Three, Digits::Four, Digits::Zero]);
+        assert_eq!(
+            BigNumber::from_str("0.5").unwrap().integer_digits().len(),
+            1
+        );
+
+        let rebuilt = BigNumber::from_digits(Sign::Negative, integer, fraction);
+        assert_eq!(rebuilt, x);
+        assert_eq!(rebuilt.to_string(), "-12.340");
+        let zero = BigNumber::from_digits(Sign::Negative, [Digits::Zero], [Digits::Zero]);
+        assert!(!zero.is_negative());
+
+        let collected: BigNumber = [Digits::Zero, Digits::Four, Digits::Two]
+            .into_iter()
+            .collect();
+        assert_eq!(collected, BigNumber::from_str("42").unwrap());
+        let empty: BigNumber = Vec::new().into_iter().collect();
+        assert!(empty.is_zero());
+    }
+
+    #[test]
+    fn test_big_number_from_u128() {
+        let test_data = [
+            (0, "0"),
+            (9, "9"),
+            (10, "10"),
+            (1000, "1000"),
+            (u128::MAX, "340282366920938463463374607431768211455"),
+        ];
+        for (x, expected) in test_data {
+            assert_eq!(BigNumber::from(x).to_string(), expected);
+        }
+    }
 }
@@ -0,0 +1,449 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::Digits;
+use super::MathErrors;
+
+// Magnitudes are unsigned integers stored as slices of Digits with the most
+// significant digit first, the same layout used by the integer portion of a
+// BigNumber. Every function here returns its result without leading zeros,
+// so zero is returned as an empty Vec.
+
+const DIGITS: [Digits; 10] = [
+    Digits::Zero,
+    Digits::One,
+    Digits::Two,
+    Digits::Three,
+    Digits::Four,
+    Digits::Five,
+    Digits::Six,
+    Digits::Seven,
+    Digits::Eight,
+    Digits::Nine,
+];
+
+/// Returns the Digit for a value that is already known to be less than ten.
+pub const fn digit(value: usize) -> Digits {
+    return DIGITS[value];
+}
+
+/// Returns the part of x that follows any leading zeros.
+pub fn significant(x: &[Digits]) -> &[Digits] {
+    match x.iter().position(|d| *d != Digits::Zero) {
+        Some(start) => return &x[start..],
+        None => return &x[x.len()..],
+    }
+}
+
+/// Removes any leading zeros from x.
+pub fn trim(x: &mut Vec<Digits>) {
+    let zeros: usize = x.len() - significant(x).len();
+    x.drain(..zeros);
+}
+
+pub fn is_zero(x: &[Digits]) -> bool {
+    return x.iter().all(|d| *d == Digits::Zero);
+}
+
+/// Compares the values of x and y, ignoring any leading zeros.
+pub fn compare(x: &[Digits], y: &[Digits]) -> Ordering {
+    let x: &[Digits] = significant(x);
+    let y: &[Digits] = significant(y);
+    match x.len().cmp(&y.len()) {
+        Ordering::Equal => return x.cmp(y),
+        order => return order,
+    }
+}
+
+pub fn from_u128(input: u128) -> Vec<Digits> {
+    let mut x: u128 = input;
+    let mut result: Vec<Digits> = Vec::with_capacity(39);
+    while x > 0 {
+        result.push(digit((x % 10) as usize));
+        x /= 10;
+    }
+    result.reverse();
+    return result;
+}
+
+/// Returns None if x does not fit in a u128.
+pub fn to_u128(x: &[Digits]) -> Option<u128> {
+    let mut result: u128 = 0;
+    for d in significant(x) {
+        result = result.checked_mul(10)?.checked_add(d.to_usize() as u128)?;
+    }
+    return Some(result);
+}
+
+/// Returns x as big endian bytes, with no bytes at all for zero.
+pub fn to_be_bytes(x: &[Digits]) -> Vec<u8> {
+    let mut x: Vec<Digits> = significant(x).to_vec();
+    let mut result: Vec<u8> = Vec::new();
+    while !x.is_empty() {
+        // Dividing by 256 cannot fail.
+        let (quotient, remainder) = divide_small(&x, 256).unwrap();
+        result.push(remainder as u8);
+        x = quotient;
+    }
+    result.reverse();
+    return result;
+}
+
+/// Reads big endian bytes of any length as an unsigned integer.
+///
+/// The bytes are taken 32 bits at a time into limbs of nine digits, which
+/// is far quicker than working a digit at a time. The time still grows with
+/// the square of the length, so callers reading untrusted input should
+/// bound it.
+pub fn from_be_bytes(bytes: &[u8]) -> Vec<Digits> {
+    const LIMB: u64 = 1_000_000_000;
+    // Least significant limb first.
+    let mut limbs: Vec<u64> = Vec::new();
+    let head: usize = bytes.len() % 4;
+    let chunks = std::iter::once(&bytes[..head]).chain(bytes[head..].chunks(4));
+    for chunk in chunks {
+        let bits: usize = 8 * chunk.len();
+        // A limb shifted by 32 bits plus the carry stays below 2^63.
+        let mut carry: u64 = chunk
+            .iter()
+            .fold(0, |total, byte| total << 8 | *byte as u64);
+        for limb in limbs.iter_mut() {
+            let total: u64 = (*limb << bits) + carry;
+            *limb = total % LIMB;
+            carry = total / LIMB;
+        }
+        while carry > 0 {
+            limbs.push(carry % LIMB);
+            carry /= LIMB;
+        }
+    }
+    let mut result: Vec<Digits> = Vec::with_capacity(9 * limbs.len());
+    for limb in limbs.iter().rev() {
+        let mut value: u64 = *limb;
+        let mut digits: [Digits; 9] = [Digits::Zero; 9];
+        for d in digits.iter_mut().rev() {
+            *d = digit((value % 10) as usize);
+            value /= 10;
+        }
+        result.extend_from_slice(&digits);
+    }
+    trim(&mut result);
+    return result;
+}
+
+/// Returns ten raised to the power of exponent.
+pub fn power_of_ten(exponent: usize) -> Vec<Digits> {
+    let mut result: Vec<Digits> = vec![Digits::Zero; exponent + 1];
+    result[0] = Digits::One;
+    return result;
+}
+
+/// Multiplies x by ten raised to the power of exponent.
+pub fn shift_left(x: &[Digits], exponent: usize) -> Vec<Digits> {
+    let mut result: Vec<Digits> = significant(x).to_vec();
+    if !result.is_empty() {
+        result.resize(result.len() + exponent, Digits::Zero);
+    }
+    return result;
+}
+
+pub fn add(x: &[Digits], y: &[Digits]) -> Vec<Digits> {
+    let mut result: Vec<Digits> = Vec::with_capacity(x.len().max(y.len()) + 1);
+    let mut xs = x.iter().rev();
+    let mut ys = y.iter().rev();
+    let mut carry: Digits = Digits::Zero;
+    let mut temp: Digits;
+    loop {
+        match (xs.next(), ys.next()) {
+            (None, None) => break,
+            (a, b) => {
+                (temp, carry) = a
+                    .unwrap_or_default()
+                    .fused_addition(*b.unwrap_or_default(), carry);
+                result.push(temp);
+            }
+        }
+    }
+    result.push(carry);
+    result.reverse();
+    trim(&mut result);
+    return result;
+}
+
+/// Subtracts y from x.
+///
+/// x must not be smaller than y.
+pub fn subtract(x: &[Digits], y: &[Digits]) -> Vec<Digits> {
+    debug_assert!(compare(x, y) != Ordering::Less);
+    let mut result: Vec<Digits> = Vec::with_capacity(x.len());
+    let mut ys = y.iter().rev();
+    let mut borrow: Digits = Digits::Zero;
+    let mut temp: Digits;
+    for a in x.iter().rev() {
+        (temp, borrow) = a.fused_subtraction(*ys.next().unwrap_or_default(), borrow);
+        result.push(temp);
+    }
+    result.reverse();
+    trim(&mut result);
+    return result;
+}
+
+pub fn multiply(x: &[Digits], y: &[Digits]) -> Vec<Digits> {
+    let x: &[Digits] = significant(x);
+    let y: &[Digits] = significant(y);
+    if x.is_empty() || y.is_empty() {
+        return Vec::new();
+    }
+    // Columns are accumulated least significant first and carried at the end.
+    let mut columns: Vec<usize> = vec![0; x.len() + y.len()];
+    for (i, a) in x.iter().rev().enumerate() {
+        let a: usize = a.to_usize();
+        if a == 0 {
+            continue;
+        }
+        for (j, b) in y.iter().rev().enumerate() {
+            columns[i + j] += a * b.to_usize();
+        }
+    }
+    return from_columns(columns);
+}
+
+pub fn multiply_small(x: &[Digits], y: u32) -> Vec<Digits> {
+    let mut columns: Vec<usize> = Vec::with_capacity(x.len() + 10);
+    for a in x.iter().rev() {
+        columns.push(a.to_usize() * y as usize);
+    }
+    return from_columns(columns);
+}
+
+/// Carries a least significant first list of column sums into Digits.
+fn from_columns(columns: Vec<usize>) -> Vec<Digits> {
+    let mut result: Vec<Digits> = Vec::with_capacity(columns.len() + 10);
+    let mut carry: usize = 0;
+    for column in columns {
+        let total: usize = column + carry;
+        result.push(digit(total % 10));
+        carry = total / 10;
+    }
+    while carry > 0 {
+        result.push(digit(carry % 10));
+        carry /= 10;
+    }
+    result.reverse();
+    trim(&mut result);
+    return result;
+}
+
+/// Divides x by y returning the quotient and the remainder.
+pub fn divide_small(x: &[Digits], y: u32) -> Result<(Vec<Digits>, u32), MathErrors> {
+    if y == 0 {
+        return Err(MathErrors::DivisionByZero);
+    }
+    let y: u64 = y as u64;
+    let mut quotient: Vec<Digits> = Vec::with_capacity(x.len());
+    let mut remainder: u64 = 0;
+    for a in x {
+        remainder = remainder * 10 + a.to_usize() as u64;
+        quotient.push(digit((remainder / y) as usize));
+        remainder %= y;
+    }
+    trim(&mut quotient);
+    return Ok((quotient, remainder as u32));
+}
+
+/// Divides x by y returning the quotient and the remainder.
+///
+/// [MathErrors::DivisionByZero] is returned if y is 0.
+pub fn divide(x: &[Digits], y: &[Digits]) -> Result<(Vec<Digits>, Vec<Digits>), MathErrors> {
+    let x: &[Digits] = significant(x);
+    let y: &[Digits] = significant(y);
+    if y.is_empty() {
+        return Err(MathErrors::DivisionByZero);
+    }
+    if compare(x, y) == Ordering::Less {
+        return Ok((Vec::new(), x.to_vec()));
+    }
+    // The leading digits of the divisor give an estimate of each quotient
+    // digit that is never too small and rarely too large.
+    let lead_length: usize = y.len().min(17);
+    let lead: u64 = leading_value(y, lead_length);
+    let mut quotient: Vec<Digits> = Vec::with_capacity(x.len());
+    let mut remainder: Vec<Digits> = Vec::with_capacity(y.len() + 1);
+    for a in x {
+        remainder.push(*a);
+        trim(&mut remainder);
+        if compare(&remainder, y) == Ordering::Less {
+            quotient.push(Digits::Zero);
+            continue;
+        }
+        let top: u64 = leading_value(&remainder, lead_length + remainder.len() - y.len());
+        let mut estimate: u32 = ((top + 1) / lead).min(9) as u32;
+        let mut product: Vec<Digits> = multiply_small(y, estimate);
+        while compare(&product, &remainder) == Ordering::Greater {
+            estimate -= 1;
+            product = subtract(&product, y);
+        }
+        remainder = subtract(&remainder, &product);
+        quotient.push(digit(estimate as usize));
+    }
+    trim(&mut quotient);
+    return Ok((quotient, remainder));
+}
+
+fn leading_value(x: &[Digits], length: usize) -> u64 {
+    let mut result: u64 = 0;
+    for d in x.iter().take(length) {
+        result = result * 10 + d.to_usize() as u64;
+    }
+    return result;
+}
+
+/// Returns the largest integer whose square is not greater than x.
+pub fn square_root(x: &[Digits]) -> Vec<Digits> {
+    let x: &[Digits] = significant(x);
+    if x.is_empty() {
+        return Vec::new();
+    }
+    // Newton's method converges from above when started above the root.
+    let mut root: Vec<Digits> = power_of_ten(x.len() / 2 + 1);
+    loop {
+        let (quotient, _) = divide(x, &root).unwrap();
+        let (next, _) = divide_small(&add(&root, &quotient), 2).unwrap();
+        if compare(&next, &root) != Ordering::Less {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// Drops the last count digits of x rounding half to even.
+pub fn round_half_even(x: &[Digits], count: usize) -> Vec<Digits> {
+    if count == 0 {
+        let mut result: Vec<Digits> = x.to_vec();
+        trim(&mut result);
+        return result;
+    }
+    if count > x.len() {
+        return Vec::new();
+    }
+    let (kept, dropped) = x.split_at(x.len() - count);
+    let first: Digits = dropped[0];
+    let round_up: bool = match first.cmp(&Digits::Five) {
+        Ordering::Less => false,
+        Ordering::Greater => true,
+        Ordering::Equal => {
+            !is_zero(&dropped[1..]) || kept.last().is_some_and(|d| d.to_usize() % 2 == 1)
+        }
+    };
+    if round_up {
+        return add(kept, &[Digits::One]);
+    }
+    let mut result: Vec<Digits> = kept.to_vec();
+    trim(&mut result);
+    return result;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_magnitude_multiply() {
+        let test_data = [
+            (0, 12345, 0),
+            (9, 9, 81),
+            (123, 456, 56088),
+            (99999, 99999, 9999800001),
+            (1000, 10, 10000),
+        ];
+        for (x, y, expected) in test_data {
+            assert_eq!(
+                to_u128(&multiply(&from_u128(x), &from_u128(y))),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_magnitude_divide() {
+        let test_data = [
+            (56088, 456, Ok((123, 0))),
+            (100, 7, Ok((14, 2))),
+            (5, 10, Ok((0, 5))),
+            (9999800001, 99999, Ok((99999, 0))),
+            (
+                123456789012345678901234567890,
+                987654321,
+                Ok((124999998873437499901, 574845669)),
+            ),
+            (1, 0, Err(MathErrors::DivisionByZero)),
+        ];
+        for (x, y, expected) in test_data {
+            let result = divide(&from_u128(x), &from_u128(y))
+                .map(|(q, r)| (to_u128(&q).unwrap(), to_u128(&r).unwrap()));
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_magnitude_be_bytes() {
+        let test_data = [0, 1, 255, 256, 999999999, 1000000000, 1 << 64, u128::MAX];
+        for x in test_data {
+            let bytes: Vec<u8> = to_be_bytes(&from_u128(x));
+            let start: usize = x.leading_zeros() as usize / 8;
+            assert_eq!(bytes, x.to_be_bytes()[start..]);
+            assert_eq!(to_u128(&from_be_bytes(&bytes)), Some(x));
+            assert_eq!(to_u128(&from_be_bytes(&x.to_be_bytes())), Some(x));
+        }
+        // 256^40 has 97 digits, more than fit in any primitive.
+        let mut bytes: Vec<u8> = vec![0; 41];
+        bytes[0] = 1;
+        let expected: Vec<Digits> = multiply(&from_u128(1 << 80), &from_u128(1 << 80));
+        let expected: Vec<Digits> = multiply(&expected, &from_u128(1 << 80));
+        assert_eq!(
+            from_be_bytes(&bytes),
+            multiply(&expected, &from_u128(1 << 80))
+        );
+        assert_eq!(to_be_bytes(&from_be_bytes(&bytes)), bytes);
+    }
+
+    #[test]
+    fn test_magnitude_square_root() {
+        let test_data = [
+            (0, 0),
+            (1, 1),
+            (3, 1),
+            (4, 2),
+            (99, 9),
+            (100, 10),
+            (
+                152415787532388367501905199875019052100,
+                12345678901234567890,
+            ),
+        ];
+        for (x, expected) in test_data {
+            assert_eq!(to_u128(&square_root(&from_u128(x))), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_magnitude_round_half_even() {
+        let test_data = [
+            (12345, 1, 1234),
+            (12355, 1, 1236),
+            (12346, 1, 1235),
+            (12351, 2, 124),
+            (12250, 2, 122),
+            (99999, 2, 1000),
+            (4, 3, 0),
+        ];
+        for (x, count, expected) in test_data {
+            assert_eq!(
+                to_u128(&round_half_even(&from_u128(x), count)),
+                Some(expected)
+            );
+        }
+    }
+}
@@ -0,0 +1,240 @@
+#![warn(dead_code)]
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+// Bytes used by a group of 0 to 9 digits.
+const DIGITS_TO_BYTES: [usize; 10] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4];
+
+// The largest DECIMAL(M,D) declaration MySQL allows.
+const MAX_PRECISION: usize = 65;
+const MAX_SCALE: usize = 30;
+
+/// Returns how many digits each group of a DECIMAL(precision,scale) holds,
+/// in the order they are stored.
+///
+/// The integer digits are split into groups of nine counting from the
+/// decimal point, with any leftover digits in a short group at the front.
+/// The decimal digits are split the same way with the short group at the
+/// back.
+fn groups(precision: usize, scale: usize) -> Result<Vec<usize>, MathErrors> {
+    if precision == 0 || precision > MAX_PRECISION || scale > MAX_SCALE || scale > precision {
+        return Err(MathErrors::DomainError);
+    }
+    let integer_digits: usize = precision - scale;
+    let leading: usize = integer_digits % 9;
+    let trailing: usize = scale % 9;
+    let mut result: Vec<usize> = Vec::new();
+    if leading > 0 {
+        result.push(leading);
+    }
+    result.resize(result.len() + integer_digits / 9 + scale / 9, 9);
+    if trailing > 0 {
+        result.push(trailing);
+    }
+    return Ok(result);
+}
+
+impl BigNumber {
+    /// Returns how many bytes a DECIMAL(precision,scale) takes in the MySQL
+    /// binary format.
+    ///
+    /// [MathErrors::DomainError] is returned if MySQL does not allow the
+    /// declaration.
+    pub fn mysql_decimal_length(precision: usize, scale: usize) -> Result<usize, MathErrors> {
+        let groups: Vec<usize> = groups(precision, scale)?;
+        return Ok(groups.iter().map(|digits| DIGITS_TO_BYTES[*digits]).sum());
+    }
+
+    /// Encodes self as a MySQL DECIMAL(precision,scale) in the binary format
+    /// used on disk and in row based binary logs.
+    ///
+    /// Each group of up to nine digits is stored as a big endian integer in
+    /// as few bytes as it needs. Negative numbers have every bit inverted and
+    /// the top bit of the first byte is flipped so that the bytes sort in
+    /// numeric order.
+    ///
+    /// [MathErrors::DomainError] is returned if MySQL does not allow the
+    /// declaration, [MathErrors::Overflow] if self has too many integer
+    /// digits and [MathErrors::PrecisionLoss] if self has nonzero digits
+    /// beyond the scale.
+    pub fn to_mysql_decimal(
+        self: &Self,
+        precision: usize,
+        scale: usize,
+    ) -> Result<Vec<u8>, MathErrors> {
+        let groups: Vec<usize> = groups(precision, scale)?;
+        let integer: &[Digits] = magnitude::significant(&self.integer);
+        if integer.len() > precision - scale {
+            return Err(MathErrors::Overflow);
+        }
+        if scale < self.decimal.len() && !magnitude::is_zero(&self.decimal[scale..]) {
+            return Err(MathErrors::PrecisionLoss);
+        }
+        let mut digits: Vec<Digits> = vec![Digits::Zero; precision - scale - integer.len()];
+        digits.extend_from_slice(integer);
+        digits.extend(self.decimal.iter().take(scale));
+        digits.resize(precision, Digits::Zero);
+
+        let mut result: Vec<u8> =
+            Vec::with_capacity(BigNumber::mysql_decimal_length(precision, scale)?);
+        let mut start: usize = 0;
+        for count in groups {
+            let value: u32 = digits[start..start + count]
+                .iter()
+                .fold(0, |total, d| total * 10 + d.to_usize() as u32);
+            result.extend_from_slice(&value.to_be_bytes()[4 - DIGITS_TO_BYTES[count]..]);
+            start += count;
+        }
+        if self.is_negative() && !self.is_zero() {
+            for byte in result.iter_mut() {
+                *byte = !*byte;
+            }
+        }
+        result[0] ^= 0x80;
+        return Ok(result);
+    }
+
+    /// Decodes a MySQL DECIMAL(precision,scale) from the binary format used
+    /// on disk and in row based binary logs, see [BigNumber::to_mysql_decimal].
+    ///
+    /// The result always has the declared scale. [MathErrors::DomainError] is
+    /// returned if MySQL does not allow the declaration and
+    /// [MathErrors::ParseError] if the bytes are the wrong length or a group
+    /// holds too many digits.
+    pub fn from_mysql_decimal(
+        bytes: &[u8],
+        precision: usize,
+        scale: usize,
+    ) -> Result<BigNumber, MathErrors> {
+        let groups: Vec<usize> = groups(precision, scale)?;
+        if bytes.len() != BigNumber::mysql_decimal_length(precision, scale)? {
+            return Err(MathErrors::ParseError);
+        }
+        let sign: Sign = if bytes[0] & 0x80 == 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let mask: u8 = if sign.is_negative() { 0xFF } else { 0x00 };
+        let mut data: Vec<u8> = bytes.iter().map(|byte| byte ^ mask).collect();
+        data[0] ^= 0x80;
+
+        let mut digits: Vec<Digits> = Vec::with_capacity(precision);
+        let mut start: usize = 0;
+        for count in groups {
+            let size: usize = DIGITS_TO_BYTES[count];
+            let value: u32 = data[start..start + size]
+                .iter()
+                .fold(0, |total, byte| total << 8 | *byte as u32);
+            if value >= 10u32.pow(count as u32) {
+                return Err(MathErrors::ParseError);
+            }
+            // Adding 10^count keeps the leading zeros of the group.
+            let group: Vec<Digits> = magnitude::from_u128(value as u128 + 10u128.pow(count as u32));
+            digits.extend_from_slice(&group[1..]);
+            start += size;
+        }
+        return Ok(BigNumber::from_coefficient(sign, digits, scale));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_mysql_decimal() {
+        // (value, precision, scale, bytes)
+        let test_data: [(&str, usize, usize, &[u8]); 10] = [
+            // The worked example from the comments in MySQL's decimal.c.
+            (
+                "1234567890.1234",
+                14,
+                4,
+                &[0x81, 0x0D, 0xFB, 0x38, 0xD2, 0x04, 0xD2],
+            ),
+            (
+                "-1234567890.1234",
+                14,
+                4,
+                &[0x7E, 0xF2, 0x04, 0xC7, 0x2D, 0xFB, 0x2D],
+            ),
+            ("0.00", 10, 2, &[0x80, 0x00, 0x00, 0x00, 0x00]),
+            ("1.50", 10, 2, &[0x80, 0x00, 0x00, 0x01, 0x32]),
+            ("-1.50", 10, 2, &[0x7F, 0xFF, 0xFF, 0xFE, 0xCD]),
+            ("12345", 5, 0, &[0x80, 0x30, 0x39]),
+            (
+                "123456789.987654321",
+                18,
+                9,
+                &[0x87, 0x5B, 0xCD, 0x15, 0x3A, 0xDE, 0x68, 0xB1],
+            ),
+            ("0.5", 1, 1, &[0x85]),
+            ("-9", 1, 0, &[0x76]),
+            (
+                "-99999999999999999999.9999999999",
+                30,
+                10,
+                &[
+                    0x1C, 0xC4, 0x65, 0x36, 0x00, 0xC4, 0x65, 0x36, 0x00, 0xC4, 0x65, 0x36, 0x00,
+                    0xF6,
+                ],
+            ),
+        ];
+        for (value, precision, scale, bytes) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_mysql_decimal(precision, scale).unwrap(), bytes);
+            let decoded = BigNumber::from_mysql_decimal(bytes, precision, scale).unwrap();
+            assert_eq!(decoded.to_string(), value);
+            assert_eq!(
+                BigNumber::mysql_decimal_length(precision, scale),
+                Ok(bytes.len())
+            );
+        }
+    }
+
+    #[test]
+    fn test_mysql_decimal_scale() {
+        // Values are padded or trimmed to the declared scale.
+        let x = BigNumber::from_str("2.5").unwrap();
+        let bytes: Vec<u8> = x.to_mysql_decimal(6, 3).unwrap();
+        let decoded = BigNumber::from_mysql_decimal(&bytes, 6, 3).unwrap();
+        assert_eq!(decoded.to_string(), "2.500");
+        let x = BigNumber::from_str("2.5000").unwrap();
+        assert_eq!(x.to_mysql_decimal(6, 1), Ok(vec![0x80, 0x00, 0x02, 0x05]));
+        assert_eq!(BigNumber::mysql_decimal_length(65, 30), Ok(30));
+    }
+
+    #[test]
+    fn test_mysql_decimal_errors() {
+        // (value, precision, scale, error)
+        let test_data = [
+            ("123.4", 4, 2, MathErrors::Overflow),
+            ("1.234", 4, 2, MathErrors::PrecisionLoss),
+            ("1", 0, 0, MathErrors::DomainError),
+            ("1", 66, 0, MathErrors::DomainError),
+            ("1", 40, 31, MathErrors::DomainError),
+            ("1", 4, 5, MathErrors::DomainError),
+        ];
+        for (value, precision, scale, error) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_mysql_decimal(precision, scale), Err(error));
+        }
+        let test_data: [&[u8]; 3] = [
+            &[0x80, 0x30],
+            &[0x80, 0x30, 0x39, 0x00],
+            &[0x8F, 0x42, 0x3F],
+        ];
+        for bytes in test_data {
+            assert_eq!(
+                BigNumber::from_mysql_decimal(bytes, 5, 0),
+                Err(MathErrors::ParseError)
+            );
+        }
+    }
+}
@@ -0,0 +1,106 @@
+#![warn(dead_code)]
+// External imports
+use ::num_bigint::BigInt;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// Builds a BigInt from a sign and a magnitude.
+pub(crate) fn to_bigint(sign: Sign, digits: &[Digits]) -> BigInt {
+    let bytes: Vec<u8> = digits.iter().map(|d| d.to_usize() as u8).collect();
+    let sign: ::num_bigint::Sign = if sign.is_negative() {
+        ::num_bigint::Sign::Minus
+    } else {
+        ::num_bigint::Sign::Plus
+    };
+    // Every byte is a valid base ten digit.
+    return BigInt::from_radix_be(sign, &bytes, 10).unwrap();
+}
+
+/// Splits a BigInt into a sign and a trimmed magnitude.
+pub(crate) fn from_bigint(x: &BigInt) -> (Sign, Vec<Digits>) {
+    let (sign, bytes) = x.to_radix_be(10);
+    let mut digits: Vec<Digits> = bytes
+        .iter()
+        .map(|b| magnitude::digit(*b as usize))
+        .collect();
+    magnitude::trim(&mut digits);
+    if sign == ::num_bigint::Sign::Minus {
+        return (Sign::Negative, digits);
+    }
+    return (Sign::Positive, digits);
+}
+
+impl From<&BigInt> for BigNumber {
+    fn from(input: &BigInt) -> Self {
+        let (sign, digits) = from_bigint(input);
+        return BigNumber::from_coefficient(sign, digits, 0);
+    }
+}
+
+impl From<BigInt> for BigNumber {
+    fn from(input: BigInt) -> Self {
+        return BigNumber::from(&input);
+    }
+}
+
+impl TryFrom<&BigNumber> for BigInt {
+    type Error = MathErrors;
+
+    /// [MathErrors::PrecisionLoss] is returned if the decimal portion is not
+    /// zero.
+    fn try_from(input: &BigNumber) -> Result<Self, Self::Error> {
+        if !input.is_integer() {
+            return Err(MathErrors::PrecisionLoss);
+        }
+        return Ok(to_bigint(input.sign, &input.integer));
+    }
+}
+
+impl TryFrom<BigNumber> for BigInt {
+    type Error = MathErrors;
+
+    /// [MathErrors::PrecisionLoss] is returned if the decimal portion is not
+    /// zero.
+    fn try_from(input: BigNumber) -> Result<Self, Self::Error> {
+        return BigInt::try_from(&input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_bigint_conversions() {
+        // (BigNumber, BigInt)
+        let test_data = [
+            ("0", Ok("0")),
+            (
+                "-12345678901234567890123456789",
+                Ok("-12345678901234567890123456789"),
+            ),
+            (
+                "340282366920938463463374607431768211456",
+                Ok("340282366920938463463374607431768211456"),
+            ),
+            ("42.000", Ok("42")),
+            ("42.5", Err(MathErrors::PrecisionLoss)),
+            ("-0.001", Err(MathErrors::PrecisionLoss)),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| BigInt::from_str(e).unwrap());
+            assert_eq!(BigInt::try_from(&x), expected);
+            if let Ok(y) = expected {
+                assert_eq!(BigNumber::from(&y), x);
+                assert_eq!(BigNumber::from(y).scale(), 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+#![warn(dead_code)]
+// External imports
+use ::num_traits::FromPrimitive;
+use ::num_traits::Num;
+use ::num_traits::One;
+use ::num_traits::Pow;
+use ::num_traits::Signed;
+use ::num_traits::ToPrimitive;
+use ::num_traits::Zero;
+use std::str::FromStr;
+
+// Inter crate imports
+use super::BigNumber;
+use super::MathErrors;
+
+impl Zero for BigNumber {
+    fn zero() -> BigNumber {
+        return BigNumber::zero();
+    }
+
+    fn is_zero(&self) -> bool {
+        return BigNumber::is_zero(self);
+    }
+}
+
+impl One for BigNumber {
+    fn one() -> BigNumber {
+        return BigNumber::one();
+    }
+}
+
+impl Num for BigNumber {
+    type FromStrRadixErr = MathErrors;
+
+    /// Only radix 10 is supported, [MathErrors::UnsupportedRadix] is returned
+    /// for anything else.
+    fn from_str_radix(s: &str, radix: u32) -> Result<BigNumber, MathErrors> {
+        if radix != 10 {
+            return Err(MathErrors::UnsupportedRadix);
+        }
+        return BigNumber::from_str(s);
+    }
+}
+
+impl Signed for BigNumber {
+    fn abs(&self) -> BigNumber {
+        return BigNumber::abs(self);
+    }
+
+    /// Returns zero if self is less than or equal to other and self - other
+    /// otherwise.
+    fn abs_sub(&self, other: &BigNumber) -> BigNumber {
+        if self <= other {
+            return BigNumber::zero();
+        }
+        return self.difference(other);
+    }
+
+    fn signum(&self) -> BigNumber {
+        return BigNumber::signum(self);
+    }
+
+    /// Zero is neither positive nor negative here.
+    fn is_positive(&self) -> bool {
+        return !BigNumber::is_zero(self) && BigNumber::is_positive(self);
+    }
+
+    /// Zero is neither positive nor negative here.
+    fn is_negative(&self) -> bool {
+        return !BigNumber::is_zero(self) && BigNumber::is_negative(self);
+    }
+}
+
+impl FromPrimitive for BigNumber {
+    fn from_i64(n: i64) -> Option<BigNumber> {
+        return Some(BigNumber::from(n));
+    }
+
+    fn from_u64(n: u64) -> Option<BigNumber> {
+        return Some(BigNumber::from(n));
+    }
+
+    fn from_i128(n: i128) -> Option<BigNumber> {
+        return Some(BigNumber::from(n));
+    }
+
+    fn from_u128(n: u128) -> Option<BigNumber> {
+        return Some(BigNumber::from(n));
+    }
+
+    /// Uses the shortest decimal that converts back to n, so 0.1f32 gives
+    /// 0.1. None is returned for NaN and the infinities.
+    fn from_f32(n: f32) -> Option<BigNumber> {
+        if !n.is_finite() {
+            return None;
+        }
+        return BigNumber::from_str(&n.to_string()).ok();
+    }
+
+    /// Uses the shortest decimal that converts back to n, so 0.1 gives 0.1.
+    /// None is returned for NaN and the infinities.
+    fn from_f64(n: f64) -> Option<BigNumber> {
+        if !n.is_finite() {
+            return None;
+        }
+        return BigNumber::from_str(&n.to_string()).ok();
+    }
+}
+
+impl ToPrimitive for BigNumber {
+    /// The decimal portion is dropped. None is returned if the integer
+    /// portion does not fit.
+    fn to_i64(&self) -> Option<i64> {
+        return self.integer_part().to_string().parse().ok();
+    }
+
+    /// The decimal portion is dropped. None is returned if the integer
+    /// portion does not fit.
+    fn to_u64(&self) -> Option<u64> {
+        return self.integer_part().to_string().parse().ok();
+    }
+
+    /// The decimal portion is dropped. None is returned if the integer
+    /// portion does not fit.
+    fn to_i128(&self) -> Option<i128> {
+        return self.integer_part().to_string().parse().ok();
+    }
+
+    /// The decimal portion is dropped. None is returned if the integer
+    /// portion does not fit.
+    fn to_u128(&self) -> Option<u128> {
+        return self.integer_part().to_string().parse().ok();
+    }
+
+    /// Rounds to the nearest f32. Values too large for an f32 give infinity.
+    fn to_f32(&self) -> Option<f32> {
+        return self.to_string().parse().ok();
+    }
+
+    /// Rounds to the nearest f64. Values too large for an f64 give infinity.
+    fn to_f64(&self) -> Option<f64> {
+        return self.to_string().parse().ok();
+    }
+}
+
+macro_rules! pow_unsigned {
+    ($($t:ty),*) => {$(
+        impl Pow<$t> for BigNumber {
+            type Output = BigNumber;
+
+            /// See [BigNumber::pow].
+            fn pow(self, rhs: $t) -> BigNumber {
+                return BigNumber::pow(&self, rhs as u32);
+            }
+        }
+
+        impl Pow<$t> for &BigNumber {
+            type Output = BigNumber;
+
+            /// See [BigNumber::pow].
+            fn pow(self, rhs: $t) -> BigNumber {
+                return BigNumber::pow(self, rhs as u32);
+            }
+        }
+    )*};
+}
+
+pow_unsigned!(u8, u16, u32);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Written against the traits only, the way generic numeric code is.
+    fn sum_of_squares<T: Num + Clone>(values: &[T]) -> T {
+        return values
+            .iter()
+            .fold(T::zero(), |total, x| total + x.clone() * x.clone());
+    }
+
+    #[test]
+    fn test_num() {
+        let values: Vec<BigNumber> = ["0.1", "0.2", "-0.3"]
+            .iter()
+            .map(|x| BigNumber::from_str(x).unwrap())
+            .collect();
+        assert_eq!(sum_of_squares(&values).to_string(), "0.14");
+        assert!(Zero::is_zero(&<BigNumber as Zero>::zero()));
+        assert_eq!(<BigNumber as One>::one().to_string(), "1");
+        assert_eq!(
+            BigNumber::from_str_radix("-12.5", 10),
+            Ok(BigNumber::from_str("-12.5").unwrap())
+        );
+        assert_eq!(
+            BigNumber::from_str_radix("ff", 16),
+            Err(MathErrors::UnsupportedRadix)
+        );
+        assert_eq!(
+            BigNumber::from_str_radix("1.2.3", 10),
+            Err(MathErrors::ParseError)
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        // (x, y, abs, abs_sub, signum, is_positive, is_negative)
+        let test_data = [
+            ("-2.5", "1", "2.5", "0", "-1", false, true),
+            ("2.5", "1", "2.5", "1.5", "1", true, false),
+            ("0.0", "-1", "0.0", "1.0", "0", false, false),
+        ];
+        for (x, y, abs, abs_sub, signum, positive, negative) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let y = BigNumber::from_str(y).unwrap();
+            assert_eq!(Signed::abs(&x), BigNumber::from_str(abs).unwrap());
+            assert_eq!(x.abs_sub(&y), BigNumber::from_str(abs_sub).unwrap());
+            assert_eq!(Signed::signum(&x), BigNumber::from_str(signum).unwrap());
+            assert_eq!(Signed::is_positive(&x), positive);
+            assert_eq!(Signed::is_negative(&x), negative);
+        }
+    }
+
+    #[test]
+    fn test_primitive_conversions() {
+        assert_eq!(BigNumber::from_f64(0.1).unwrap().to_string(), "0.1");
+        assert_eq!(BigNumber::from_f32(0.1).unwrap().to_string(), "0.1");
+        assert_eq!(
+            BigNumber::from_f64(-1.5e-7).unwrap().to_string(),
+            "-0.00000015"
+        );
+        assert_eq!(BigNumber::from_f64(f64::NAN), None);
+        assert_eq!(BigNumber::from_f32(f32::NEG_INFINITY), None);
+        assert_eq!(
+            BigNumber::from_i64(i64::MIN),
+            Some(BigNumber::from(i64::MIN))
+        );
+        assert_eq!(
+            BigNumber::from_u128(u128::MAX),
+            Some(BigNumber::from(u128::MAX))
+        );
+
+        // (x, to_i64, to_u64, to_f64)
+        let test_data = [
+            ("12.99", Some(12), Some(12), 12.99),
+            ("-12.99", Some(-12), None, -12.99),
+            ("-0.5", Some(0), Some(0), -0.5),
+            (
+                "9223372036854775808",
+                None,
+                Some(9223372036854775808),
+                9223372036854775808.0,
+            ),
+        ];
+        for (x, signed, unsigned, float) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.to_i64(), signed);
+            assert_eq!(x.to_u64(), unsigned);
+            assert_eq!(x.to_f64(), Some(float));
+        }
+        let huge = BigNumber::from_str("1").unwrap().shift_decimal(400);
+        assert_eq!(huge.to_f64(), Some(f64::INFINITY));
+        assert_eq!(huge.to_i128(), None);
+        assert_eq!(BigNumber::from_str("0.1").unwrap().to_f32(), Some(0.1f32));
+    }
+
+    #[test]
+    fn test_pow_trait() {
+        let x = BigNumber::from_str("1.5").unwrap();
+        assert_eq!(Pow::pow(&x, 2u8).to_string(), "2.25");
+        assert_eq!(Pow::pow(x, 3u32).to_string(), "3.375");
+    }
+}
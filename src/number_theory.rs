@@ -0,0 +1,258 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// Returns x mod m for an unsigned x and a nonzero m.
+pub fn remainder(x: &[Digits], m: &[Digits]) -> Vec<Digits> {
+    if magnitude::compare(x, m) == Ordering::Less {
+        let mut result: Vec<Digits> = x.to_vec();
+        magnitude::trim(&mut result);
+        return result;
+    }
+    let (_, result) = magnitude::divide(x, m).unwrap();
+    return result;
+}
+
+/// Returns the greatest common divisor of x and y.
+pub fn gcd(x: &[Digits], y: &[Digits]) -> Vec<Digits> {
+    let mut a: Vec<Digits> = magnitude::significant(x).to_vec();
+    let mut b: Vec<Digits> = magnitude::significant(y).to_vec();
+    while !b.is_empty() {
+        let r: Vec<Digits> = remainder(&a, &b);
+        a = b;
+        b = r;
+    }
+    return a;
+}
+
+/// Returns x y mod m.
+pub fn multiply_mod(x: &[Digits], y: &[Digits], m: &[Digits]) -> Vec<Digits> {
+    return remainder(&magnitude::multiply(x, y), m);
+}
+
+/// Returns base^exponent mod m for a nonzero m.
+///
+/// The exponent is walked one decimal digit at a time from the most
+/// significant end, raising the running result to the tenth power before
+/// multiplying in base^digit.
+pub fn pow_mod(base: &[Digits], exponent: &[Digits], m: &[Digits]) -> Vec<Digits> {
+    let one: Vec<Digits> = remainder(&[Digits::One], m);
+    let base: Vec<Digits> = remainder(base, m);
+    let mut powers: Vec<Vec<Digits>> = vec![one.clone()];
+    for index in 1..10 {
+        powers.push(multiply_mod(&powers[index - 1], &base, m));
+    }
+    let mut result: Vec<Digits> = one;
+    for digit in magnitude::significant(exponent) {
+        let square: Vec<Digits> = multiply_mod(&result, &result, m);
+        let fourth: Vec<Digits> = multiply_mod(&square, &square, m);
+        let eighth: Vec<Digits> = multiply_mod(&fourth, &fourth, m);
+        result = multiply_mod(&eighth, &square, m);
+        result = multiply_mod(&result, &powers[digit.to_usize()], m);
+    }
+    return result;
+}
+
+/// Returns the inverse of x modulo m, or None if x and m share a factor.
+pub fn inverse_mod(x: &[Digits], m: &[Digits]) -> Option<Vec<Digits>> {
+    // The extended Euclidean algorithm with the coefficients of x kept
+    // reduced modulo m so they never go negative.
+    let mut old_r: Vec<Digits> = magnitude::significant(m).to_vec();
+    let mut r: Vec<Digits> = remainder(x, m);
+    let mut old_s: Vec<Digits> = Vec::new();
+    let mut s: Vec<Digits> = remainder(&[Digits::One], m);
+    while !r.is_empty() {
+        let (q, next_r) = magnitude::divide(&old_r, &r).unwrap();
+        let step: Vec<Digits> = multiply_mod(&q, &s, m);
+        let next_s: Vec<Digits> =
+            remainder(&magnitude::subtract(&magnitude::add(&old_s, m), &step), m);
+        old_r = r;
+        r = next_r;
+        old_s = s;
+        s = next_s;
+    }
+    if old_r != [Digits::One] {
+        return None;
+    }
+    return Some(old_s);
+}
+
+impl BigNumber {
+    /// Returns the greatest common divisor of self and other.
+    ///
+    /// The result is never negative. [MathErrors::DomainError] is returned if
+    /// either value is not an integer.
+    pub fn gcd(self: &Self, other: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let result: Vec<Digits> = gcd(&self.integral()?, &other.integral()?);
+        return Ok(BigNumber::from_coefficient(Sign::Positive, result, 0));
+    }
+
+    /// Returns the least common multiple of self and other.
+    ///
+    /// The result is never negative and is zero if either value is zero.
+    /// [MathErrors::DomainError] is returned if either value is not an
+    /// integer.
+    pub fn lcm(self: &Self, other: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let x: Vec<Digits> = self.integral()?;
+        let y: Vec<Digits> = other.integral()?;
+        if x.is_empty() || y.is_empty() {
+            return Ok(BigNumber::zero());
+        }
+        let (reduced, _) = magnitude::divide(&x, &gcd(&x, &y))?;
+        let result: Vec<Digits> = magnitude::multiply(&reduced, &y);
+        return Ok(BigNumber::from_coefficient(Sign::Positive, result, 0));
+    }
+
+    /// Returns self raised to exponent modulo the absolute value of modulus.
+    ///
+    /// The result is between zero and |modulus|. A negative exponent raises
+    /// the modular inverse of self instead.
+    ///
+    /// [MathErrors::DivisionByZero] is returned if modulus is zero and
+    /// [MathErrors::DomainError] if any argument is not an integer or the
+    /// exponent is negative and self has no inverse.
+    pub fn mod_pow(
+        self: &Self,
+        exponent: &BigNumber,
+        modulus: &BigNumber,
+    ) -> Result<BigNumber, MathErrors> {
+        let m: Vec<Digits> = modulus.integral()?;
+        let power: Vec<Digits> = exponent.integral()?;
+        if m.is_empty() {
+            return Err(MathErrors::DivisionByZero);
+        }
+        let base: Vec<Digits> = if exponent.is_negative() {
+            let inverse: BigNumber = self.mod_inverse(modulus)?;
+            inverse.integral()?
+        } else {
+            self.residue(&m)?
+        };
+        let result: Vec<Digits> = pow_mod(&base, &power, &m);
+        return Ok(BigNumber::from_coefficient(Sign::Positive, result, 0));
+    }
+
+    /// Returns the inverse of self modulo the absolute value of modulus, the
+    /// value y between zero and |modulus| with self y = 1 mod modulus.
+    ///
+    /// [MathErrors::DivisionByZero] is returned if modulus is zero and
+    /// [MathErrors::DomainError] if either value is not an integer or self
+    /// and modulus are not coprime.
+    pub fn mod_inverse(self: &Self, modulus: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let m: Vec<Digits> = modulus.integral()?;
+        if m.is_empty() {
+            return Err(MathErrors::DivisionByZero);
+        }
+        let x: Vec<Digits> = self.residue(&m)?;
+        return match inverse_mod(&x, &m) {
+            Some(result) => Ok(BigNumber::from_coefficient(Sign::Positive, result, 0)),
+            None => Err(MathErrors::DomainError),
+        };
+    }
+
+    /// Returns the magnitude of self without leading zeros.
+    ///
+    /// [MathErrors::DomainError] is returned if self is not an integer.
+    pub(crate) fn integral(self: &Self) -> Result<Vec<Digits>, MathErrors> {
+        if !self.is_integer() {
+            return Err(MathErrors::DomainError);
+        }
+        return Ok(magnitude::significant(&self.integer).to_vec());
+    }
+
+    /// Returns self mod m as a value between zero and m, even for negative
+    /// self.
+    fn residue(self: &Self, m: &[Digits]) -> Result<Vec<Digits>, MathErrors> {
+        let r: Vec<Digits> = remainder(&self.integral()?, m);
+        if self.is_negative() && !r.is_empty() {
+            return Ok(magnitude::subtract(m, &r));
+        }
+        return Ok(r);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_gcd_lcm() {
+        let test_data = [
+            ("12", "18", Ok(("6", "36"))),
+            ("-12", "18", Ok(("6", "36"))),
+            ("0", "5", Ok(("5", "0"))),
+            ("0", "0", Ok(("0", "0"))),
+            ("17", "31", Ok(("1", "527"))),
+            (
+                "123456789012345678901234567890",
+                "987654321098765432109876543210",
+                Ok((
+                    "9000000000900000000090",
+                    "13548070124980948012498094801236261410",
+                )),
+            ),
+            ("12.5", "5", Err(MathErrors::DomainError)),
+            ("12.0", "4", Ok(("4", "12"))),
+        ];
+        for (x, y, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let y = BigNumber::from_str(y).unwrap();
+            let (gcd, lcm) = match expected {
+                Ok((g, l)) => (
+                    Ok(BigNumber::from_str(g).unwrap()),
+                    Ok(BigNumber::from_str(l).unwrap()),
+                ),
+                Err(e) => (Err(e), Err(e)),
+            };
+            assert_eq!(x.gcd(&y), gcd);
+            assert_eq!(x.lcm(&y), lcm);
+        }
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        let test_data = [
+            ("4", "13", "497", Ok("445")),
+            ("2", "0", "7", Ok("1")),
+            ("5", "3", "1", Ok("0")),
+            ("-2", "3", "7", Ok("6")),
+            ("3", "-1", "7", Ok("5")),
+            ("2", "-1", "4", Err(MathErrors::DomainError)),
+            ("2", "1000", "1000000007", Ok("688423210")),
+            ("2", "10", "0", Err(MathErrors::DivisionByZero)),
+            ("2", "0.5", "7", Err(MathErrors::DomainError)),
+        ];
+        for (base, exponent, modulus, expected) in test_data {
+            let base = BigNumber::from_str(base).unwrap();
+            let exponent = BigNumber::from_str(exponent).unwrap();
+            let modulus = BigNumber::from_str(modulus).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(base.mod_pow(&exponent, &modulus), expected);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        let test_data = [
+            ("3", "11", Ok("4")),
+            ("10", "17", Ok("12")),
+            ("-3", "11", Ok("7")),
+            ("6", "9", Err(MathErrors::DomainError)),
+            ("3", "0", Err(MathErrors::DivisionByZero)),
+            ("1.5", "7", Err(MathErrors::DomainError)),
+        ];
+        for (x, modulus, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let modulus = BigNumber::from_str(modulus).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.mod_inverse(&modulus), expected);
+        }
+    }
+}
@@ -0,0 +1,500 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+use std::cmp::Ordering;
+use std::iter;
+use std::iter::Product;
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::AddAssign;
+use std::ops::Div;
+use std::ops::DivAssign;
+use std::ops::Mul;
+use std::ops::MulAssign;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::RemAssign;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::Sign;
+
+impl BigNumber {
+    /// Compares the magnitudes of self and rhs, ignoring their signs.
+    fn compare_magnitude(self: &Self, rhs: &BigNumber) -> Ordering {
+        let x: &[Digits] = magnitude::significant(&self.integer);
+        let y: &[Digits] = magnitude::significant(&rhs.integer);
+        match x.len().cmp(&y.len()).then_with(|| x.cmp(y)) {
+            Ordering::Equal => {}
+            order => return order,
+        }
+        let length: usize = cmp::max(self.decimal.len(), rhs.decimal.len());
+        for i in 0..length {
+            let a: Digits = self.decimal.get(i).copied().unwrap_or_default();
+            let b: Digits = rhs.decimal.get(i).copied().unwrap_or_default();
+            match a.cmp(&b) {
+                Ordering::Equal => {}
+                order => return order,
+            }
+        }
+        return Ordering::Equal;
+    }
+
+    /// Adds rhs, or subtracts it when subtract is true, working in the digit
+    /// buffers self already has. They only grow when the result needs more
+    /// digits, so accumulating many values does not allocate at every step.
+    fn accumulate(self: &mut Self, rhs: &BigNumber, subtract: bool) {
+        let rhs_sign: Sign = if subtract { !rhs.sign } else { rhs.sign };
+        // Line up the decimal points and leave room in front for a carry.
+        if self.decimal.len() < rhs.decimal.len() {
+            self.decimal.resize(rhs.decimal.len(), Digits::Zero);
+        }
+        let width: usize = cmp::max(self.integer.len(), rhs.integer.len()) + 1;
+        if self.integer.len() < width {
+            let zeros = iter::repeat_n(Digits::Zero, width - self.integer.len());
+            self.integer.splice(0..0, zeros);
+        }
+        // With different signs the smaller magnitude is taken from the larger.
+        let order: Ordering = if self.sign == rhs_sign {
+            Ordering::Greater
+        } else {
+            self.compare_magnitude(rhs)
+        };
+        let adding: bool = self.sign == rhs_sign;
+
+        // The digits of rhs, least significant first, to match those of self.
+        let padding = iter::repeat_n(Digits::Zero, self.decimal.len() - rhs.decimal.len());
+        let mut others = padding
+            .chain(rhs.decimal.iter().rev().copied())
+            .chain(rhs.integer.iter().rev().copied());
+        let mut carry: Digits = Digits::Zero;
+        for digit in self
+            .decimal
+            .iter_mut()
+            .rev()
+            .chain(self.integer.iter_mut().rev())
+        {
+            let other: Digits = others.next().unwrap_or_default();
+            (*digit, carry) = if adding {
+                digit.fused_addition(other, carry)
+            } else if order == Ordering::Less {
+                other.fused_subtraction(*digit, carry)
+            } else {
+                digit.fused_subtraction(other, carry)
+            };
+        }
+        if order == Ordering::Less {
+            self.sign = rhs_sign;
+        }
+
+        magnitude::trim(&mut self.integer);
+        if self.integer.is_empty() {
+            self.integer.push(Digits::Zero);
+        }
+        if self.is_zero() {
+            self.sign = Sign::Positive;
+        }
+    }
+}
+
+impl Mul<&BigNumber> for &BigNumber {
+    type Output = BigNumber;
+
+    /// Multiplies self by rhs exactly.
+    fn mul(self, rhs: &BigNumber) -> BigNumber {
+        return self.product(rhs);
+    }
+}
+
+impl Mul for BigNumber {
+    type Output = BigNumber;
+
+    /// Multiplies self by rhs exactly.
+    fn mul(self, rhs: BigNumber) -> BigNumber {
+        return self.product(&rhs);
+    }
+}
+
+impl Div<&BigNumber> for &BigNumber {
+    type Output = BigNumber;
+
+    /// Panics if rhs is zero. See [BigNumber::operator_quotient].
+    fn div(self, rhs: &BigNumber) -> BigNumber {
+        return self.operator_quotient(rhs);
+    }
+}
+
+impl Div for BigNumber {
+    type Output = BigNumber;
+
+    /// Panics if rhs is zero. See [BigNumber::operator_quotient].
+    fn div(self, rhs: BigNumber) -> BigNumber {
+        return self.operator_quotient(&rhs);
+    }
+}
+
+impl Rem<&BigNumber> for &BigNumber {
+    type Output = BigNumber;
+
+    /// The remainder with the sign of self, see [BigNumber::rem_trunc].
+    ///
+    /// Panics if rhs is zero, just like integer division.
+    fn rem(self, rhs: &BigNumber) -> BigNumber {
+        if rhs.is_zero() {
+            panic!("attempt to calculate the remainder of a BigNumber with a divisor of zero");
+        }
+        return self.rem_trunc(rhs).unwrap();
+    }
+}
+
+impl Rem for BigNumber {
+    type Output = BigNumber;
+
+    /// The remainder with the sign of self, see [BigNumber::rem_trunc].
+    ///
+    /// Panics if rhs is zero, just like integer division.
+    fn rem(self, rhs: BigNumber) -> BigNumber {
+        return (&self).rem(&rhs);
+    }
+}
+
+// Every operator is written once for a pair of references. The mixed owned
+// and borrowed forms forward to it so only the operands that are actually
+// needed get cloned.
+macro_rules! forward_operator {
+    ($op:ident, $method:ident) => {
+        impl $op<&BigNumber> for BigNumber {
+            type Output = BigNumber;
+
+            fn $method(self, rhs: &BigNumber) -> BigNumber {
+                return (&self).$method(rhs);
+            }
+        }
+
+        impl $op<BigNumber> for &BigNumber {
+            type Output = BigNumber;
+
+            fn $method(self, rhs: BigNumber) -> BigNumber {
+                return self.$method(&rhs);
+            }
+        }
+    };
+}
+
+// The compound assignments that build a new value anyway, as multiplying and
+// dividing do.
+macro_rules! forward_assignment {
+    ($assign_op:ident, $assign_method:ident, $method:ident) => {
+        impl $assign_op<&BigNumber> for BigNumber {
+            fn $assign_method(&mut self, rhs: &BigNumber) {
+                *self = (&*self).$method(rhs);
+            }
+        }
+
+        impl $assign_op<BigNumber> for BigNumber {
+            fn $assign_method(&mut self, rhs: BigNumber) {
+                *self = (&*self).$method(&rhs);
+            }
+        }
+    };
+}
+
+forward_operator!(Add, add);
+forward_operator!(Sub, sub);
+forward_operator!(Mul, mul);
+forward_operator!(Div, div);
+forward_operator!(Rem, rem);
+forward_assignment!(MulAssign, mul_assign, mul);
+forward_assignment!(DivAssign, div_assign, div);
+forward_assignment!(RemAssign, rem_assign, rem);
+
+impl AddAssign<&BigNumber> for BigNumber {
+    /// Adds rhs to self in place, reusing the digits self already holds.
+    fn add_assign(&mut self, rhs: &BigNumber) {
+        self.accumulate(rhs, false);
+    }
+}
+
+impl AddAssign<BigNumber> for BigNumber {
+    /// Adds rhs to self in place, reusing the digits self already holds.
+    fn add_assign(&mut self, rhs: BigNumber) {
+        self.accumulate(&rhs, false);
+    }
+}
+
+impl SubAssign<&BigNumber> for BigNumber {
+    /// Subtracts rhs from self in place, reusing the digits self already
+    /// holds.
+    fn sub_assign(&mut self, rhs: &BigNumber) {
+        self.accumulate(rhs, true);
+    }
+}
+
+impl SubAssign<BigNumber> for BigNumber {
+    /// Subtracts rhs from self in place, reusing the digits self already
+    /// holds.
+    fn sub_assign(&mut self, rhs: BigNumber) {
+        self.accumulate(&rhs, true);
+    }
+}
+
+impl BigNumber {
+    /// Returns self raised to the power exponent exactly. Zero to the power
+    /// zero is one.
+    pub fn pow(self: &Self, exponent: u32) -> BigNumber {
+        let mut result: BigNumber = BigNumber::one();
+        let mut square: BigNumber = self.clone();
+        let mut remaining: u32 = exponent;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result.product(&square);
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                square = square.product(&square);
+            }
+        }
+        return result;
+    }
+}
+
+impl Neg for BigNumber {
+    type Output = BigNumber;
+
+    fn neg(self) -> BigNumber {
+        let mut result: BigNumber = self;
+        if !result.is_zero() {
+            result.negate();
+        }
+        return result;
+    }
+}
+
+impl Neg for &BigNumber {
+    type Output = BigNumber;
+
+    fn neg(self) -> BigNumber {
+        return self.negated();
+    }
+}
+
+impl Sum for BigNumber {
+    /// Adds up the values in place with +=. An empty iterator sums to zero.
+    fn sum<I: Iterator<Item = BigNumber>>(iter: I) -> BigNumber {
+        let mut total: BigNumber = BigNumber::from_integer(0);
+        for x in iter {
+            total += &x;
+        }
+        return total;
+    }
+}
+
+impl<'a> Sum<&'a BigNumber> for BigNumber {
+    /// Adds up the values in place with +=. An empty iterator sums to zero.
+    fn sum<I: Iterator<Item = &'a BigNumber>>(iter: I) -> BigNumber {
+        let mut total: BigNumber = BigNumber::from_integer(0);
+        for x in iter {
+            total += x;
+        }
+        return total;
+    }
+}
+
+impl Product for BigNumber {
+    /// Multiplies the values together. An empty iterator gives one.
+    fn product<I: Iterator<Item = BigNumber>>(iter: I) -> BigNumber {
+        return iter.fold(BigNumber::one(), |total, x| total.product(&x));
+    }
+}
+
+impl<'a> Product<&'a BigNumber> for BigNumber {
+    /// Multiplies the values together. An empty iterator gives one.
+    fn product<I: Iterator<Item = &'a BigNumber>>(iter: I) -> BigNumber {
+        return iter.fold(BigNumber::one(), |total, x| total.product(x));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_reference_operators() {
+        // (a, b, a + b, a - b, a * b, a / b)
+        let test_data = [
+            (
+                "1.5",
+                "2.25",
+                "3.75",
+                "-0.75",
+                "3.375",
+                "0.6666666666666666666666666666666667",
+            ),
+            (
+                "-1.5",
+                "2.25",
+                "0.75",
+                "-3.75",
+                "-3.375",
+                "-0.6666666666666666666666666666666667",
+            ),
+            ("10", "-0.4", "9.6", "10.4", "-4", "-25"),
+            ("-7", "-7", "-14", "0", "49", "1"),
+            ("0", "-3", "-3", "3", "0", "0"),
+        ];
+        for (a, b, sum, difference, product, quotient) in test_data {
+            let a = BigNumber::from_str(a).unwrap();
+            let b = BigNumber::from_str(b).unwrap();
+            let sum = BigNumber::from_str(sum).unwrap();
+            let difference = BigNumber::from_str(difference).unwrap();
+            let product = BigNumber::from_str(product).unwrap();
+            let quotient = BigNumber::from_str(quotient).unwrap();
+
+            assert_eq!(&a + &b, sum);
+            assert_eq!(a.clone() + &b, sum);
+            assert_eq!(&a + b.clone(), sum);
+            assert_eq!(a.clone() + b.clone(), sum);
+            assert_eq!(&a - &b, difference);
+            assert_eq!(a.clone() - b.clone(), difference);
+            assert_eq!(&a * &b, product);
+            assert_eq!(a.clone() * b.clone(), product);
+            assert_eq!(&a / &b, quotient);
+            assert_eq!(a.clone() / b.clone(), quotient);
+
+            let mut x = a.clone();
+            x += &b;
+            assert_eq!(x, sum);
+            x -= b.clone();
+            assert_eq!(x, a);
+            x *= &b;
+            assert_eq!(x, product);
+            x /= &b;
+            assert_eq!(x, a);
+        }
+    }
+
+    #[test]
+    fn test_compound_assignment_in_place() {
+        // (a, b, a + b, a - b)
+        let test_data = [
+            ("999.99", "0.01", "1000.00", "999.98"),
+            ("0.01", "999.99", "1000.00", "-999.98"),
+            ("-0.5", "0.25", "-0.25", "-0.75"),
+            ("0.25", "-0.5", "-0.25", "0.75"),
+            ("12", "12.000", "24.000", "0.000"),
+            ("-12", "-12", "-24", "0"),
+            ("0", "-0.001", "-0.001", "0.001"),
+            ("100000", "-1", "99999", "100001"),
+        ];
+        for (a, b, sum, difference) in test_data {
+            let a = BigNumber::from_str(a).unwrap();
+            let b = BigNumber::from_str(b).unwrap();
+            let mut x = a.clone();
+            x += &b;
+            assert_eq!(x.to_string(), sum);
+            assert_eq!(x, a.sum(&b));
+            let mut x = a.clone();
+            x -= &b;
+            assert_eq!(x.to_string(), difference);
+            assert_eq!(x, a.difference(&b));
+        }
+
+        // Once the buffers are large enough they are kept.
+        let mut total = BigNumber::from_str("1000000.00").unwrap();
+        let item = BigNumber::from_str("-0.01").unwrap();
+        total += &item;
+        let integer: *const Digits = total.integer.as_ptr();
+        let decimal: *const Digits = total.decimal.as_ptr();
+        for _ in 0..1000 {
+            total += &item;
+        }
+        assert_eq!(total.to_string(), "999989.99");
+        assert_eq!(total.integer.as_ptr(), integer);
+        assert_eq!(total.decimal.as_ptr(), decimal);
+    }
+
+    #[test]
+    fn test_remainder_operator() {
+        let test_data = [
+            ("7.5", "2", "1.5"),
+            ("-7.5", "2", "-1.5"),
+            ("7.5", "-2", "1.5"),
+            ("0.1", "0.03", "0.01"),
+            ("6", "3", "0"),
+        ];
+        for (a, b, expected) in test_data {
+            let a = BigNumber::from_str(a).unwrap();
+            let b = BigNumber::from_str(b).unwrap();
+            let expected = BigNumber::from_str(expected).unwrap();
+            assert_eq!(&a % &b, expected);
+            assert_eq!(a.clone() % b.clone(), expected);
+            let mut x = a.clone();
+            x %= b;
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "attempt to calculate the remainder of a BigNumber with a divisor of zero"
+    )]
+    fn test_remainder_by_zero() {
+        let _ = BigNumber::from_str("1.5").unwrap() % BigNumber::from_str("0").unwrap();
+    }
+
+    #[test]
+    fn test_pow() {
+        let test_data = [
+            ("1.1", 2, "1.21"),
+            ("-2", 3, "-8"),
+            ("-2", 4, "16"),
+            ("0.5", 10, "0.0009765625"),
+            ("10", 0, "1"),
+            ("0", 0, "1"),
+            ("0", 5, "0"),
+            ("2", 100, "1267650600228229401496703205376"),
+        ];
+        for (x, exponent, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            assert_eq!(x.pow(exponent), BigNumber::from_str(expected).unwrap());
+        }
+        // The result keeps every digit of the exact product.
+        assert_eq!(
+            BigNumber::from_str("1.10").unwrap().pow(3).to_string(),
+            "1.331000"
+        );
+    }
+
+    #[test]
+    fn test_negation() {
+        let x = BigNumber::from_str("2.5").unwrap();
+        assert_eq!(-&x, BigNumber::from_str("-2.5").unwrap());
+        assert_eq!(-(-x.clone()), x);
+        assert!(!(-BigNumber::from_str("0.0").unwrap()).is_negative());
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let items: Vec<BigNumber> = ["19.99", "-5.01", "0.02", "100"]
+            .iter()
+            .map(|x| BigNumber::from_str(x).unwrap())
+            .collect();
+        let total: BigNumber = items.iter().sum();
+        assert_eq!(total, BigNumber::from_str("115").unwrap());
+        let owned_total: BigNumber = items.clone().into_iter().sum();
+        assert_eq!(owned_total, total);
+        let product: BigNumber = items.iter().product();
+        assert_eq!(product, BigNumber::from_str("-200.2998").unwrap());
+        let owned_product: BigNumber = items.into_iter().product();
+        assert_eq!(owned_product, product);
+
+        let empty: Vec<BigNumber> = Vec::new();
+        assert_eq!(empty.iter().sum::<BigNumber>().to_string(), "0");
+        assert_eq!(empty.iter().product::<BigNumber>().to_string(), "1");
+    }
+}
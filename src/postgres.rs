@@ -0,0 +1,344 @@
+#![warn(dead_code)]
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+// The sign word of the binary NUMERIC format.
+const POSITIVE: u16 = 0x0000;
+const NEGATIVE: u16 = 0x4000;
+const NAN: u16 = 0xC000;
+const INFINITY: u16 = 0xD000;
+const NEGATIVE_INFINITY: u16 = 0xF000;
+
+// The largest display scale PostgreSQL accepts.
+const MAX_DSCALE: usize = 0x3FFF;
+
+/// A PostgreSQL NUMERIC value, which can also be NaN or infinite.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PgNumeric {
+    Number(BigNumber),
+    NaN,
+    Infinity,
+    NegativeInfinity,
+}
+
+impl From<BigNumber> for PgNumeric {
+    fn from(input: BigNumber) -> Self {
+        return PgNumeric::Number(input);
+    }
+}
+
+impl PgNumeric {
+    /// Encodes the value in the binary format PostgreSQL uses for NUMERIC.
+    ///
+    /// The header is four big endian 16 bit words: the number of base 10000
+    /// digit groups, the weight of the first group, the sign and the display
+    /// scale. The groups follow, most significant first, with leading and
+    /// trailing zero groups left out.
+    ///
+    /// [MathErrors::Overflow] is returned if the scale or the number of
+    /// integer digits is more than NUMERIC allows.
+    pub fn to_bytes(self: &Self) -> Result<Vec<u8>, MathErrors> {
+        let x: &BigNumber = match self {
+            PgNumeric::Number(x) => x,
+            PgNumeric::NaN => return Ok(header(0, 0, NAN, 0)),
+            PgNumeric::Infinity => return Ok(header(0, 0, INFINITY, 0)),
+            PgNumeric::NegativeInfinity => return Ok(header(0, 0, NEGATIVE_INFINITY, 0)),
+        };
+        let scale: usize = x.scale();
+        if scale > MAX_DSCALE {
+            return Err(MathErrors::Overflow);
+        }
+        // Pad both portions out to whole groups on either side of the
+        // decimal point.
+        let integer: &[Digits] = magnitude::significant(&x.integer);
+        let mut digits: Vec<Digits> = vec![Digits::Zero; (4 - integer.len() % 4) % 4];
+        digits.extend_from_slice(integer);
+        let integer_groups: usize = digits.len() / 4;
+        digits.extend_from_slice(&x.decimal);
+        digits.resize(digits.len().div_ceil(4) * 4, Digits::Zero);
+        let mut groups: Vec<u16> = digits
+            .chunks(4)
+            .map(|group| {
+                group
+                    .iter()
+                    .fold(0, |total, d| total * 10 + d.to_usize() as u16)
+            })
+            .collect();
+
+        let leading: usize = groups.iter().take_while(|group| **group == 0).count();
+        groups.drain(..leading);
+        while groups.last() == Some(&0) {
+            groups.pop();
+        }
+        let mut weight: isize = integer_groups as isize - 1 - leading as isize;
+        if groups.is_empty() {
+            weight = 0;
+        }
+        if weight > i16::MAX as isize || groups.len() > i16::MAX as usize {
+            return Err(MathErrors::Overflow);
+        }
+
+        let sign: u16 = if x.is_negative() { NEGATIVE } else { POSITIVE };
+        let mut result: Vec<u8> = header(groups.len() as u16, weight as i16, sign, scale as u16);
+        for group in groups {
+            result.extend_from_slice(&group.to_be_bytes());
+        }
+        return Ok(result);
+    }
+
+    /// Decodes the binary format PostgreSQL uses for NUMERIC, see
+    /// [PgNumeric::to_bytes].
+    ///
+    /// The result has exactly the display scale given in the header.
+    /// [MathErrors::ParseError] is returned if the bytes are not a valid
+    /// NUMERIC.
+    pub fn from_bytes(bytes: &[u8]) -> Result<PgNumeric, MathErrors> {
+        if bytes.len() < 8 {
+            return Err(MathErrors::ParseError);
+        }
+        let word = |index: usize| -> u16 {
+            return u16::from_be_bytes([bytes[2 * index], bytes[2 * index + 1]]);
+        };
+        let count: i16 = word(0) as i16;
+        let weight: i16 = word(1) as i16;
+        let dscale: usize = word(3) as usize;
+        if count < 0 || bytes.len() != 8 + 2 * count as usize || dscale > MAX_DSCALE {
+            return Err(MathErrors::ParseError);
+        }
+        let sign: Sign = match word(2) {
+            POSITIVE => Sign::Positive,
+            NEGATIVE => Sign::Negative,
+            NAN => return Ok(PgNumeric::NaN),
+            INFINITY => return Ok(PgNumeric::Infinity),
+            NEGATIVE_INFINITY => return Ok(PgNumeric::NegativeInfinity),
+            _ => return Err(MathErrors::ParseError),
+        };
+
+        let mut digits: Vec<Digits> = Vec::with_capacity(4 * count as usize);
+        for index in 0..count as usize {
+            let group: u16 = word(4 + index);
+            if group >= 10000 {
+                return Err(MathErrors::ParseError);
+            }
+            digits.extend(magnitude::from_u128(group as u128 + 10000).drain(1..));
+        }
+        // The last group counts units of 10^exponent.
+        let exponent: isize = 4 * (weight as isize - count as isize + 1);
+        let mut scale: usize = 0;
+        if exponent >= 0 {
+            digits.resize(digits.len() + exponent as usize, Digits::Zero);
+        } else {
+            scale = exponent.unsigned_abs();
+        }
+        // Only zeros are added or dropped to reach the display scale.
+        while scale < dscale {
+            digits.push(Digits::Zero);
+            scale += 1;
+        }
+        while scale > dscale && digits.last() == Some(&Digits::Zero) {
+            digits.pop();
+            scale -= 1;
+        }
+        return Ok(PgNumeric::Number(BigNumber::from_coefficient(
+            sign, digits, scale,
+        )));
+    }
+}
+
+/// Builds the eight byte header of a NUMERIC.
+fn header(count: u16, weight: i16, sign: u16, dscale: u16) -> Vec<u8> {
+    let mut result: Vec<u8> = Vec::with_capacity(8 + 2 * count as usize);
+    result.extend_from_slice(&count.to_be_bytes());
+    result.extend_from_slice(&weight.to_be_bytes());
+    result.extend_from_slice(&sign.to_be_bytes());
+    result.extend_from_slice(&dscale.to_be_bytes());
+    return result;
+}
+
+impl BigNumber {
+    /// Encodes self in the binary format PostgreSQL uses for NUMERIC, see
+    /// [PgNumeric::to_bytes].
+    pub fn to_pg_numeric(self: &Self) -> Result<Vec<u8>, MathErrors> {
+        return PgNumeric::Number(self.clone()).to_bytes();
+    }
+
+    /// Decodes the binary format PostgreSQL uses for NUMERIC, see
+    /// [PgNumeric::from_bytes].
+    ///
+    /// [MathErrors::DomainError] is returned for NaN and the infinities.
+    pub fn from_pg_numeric(bytes: &[u8]) -> Result<BigNumber, MathErrors> {
+        return match PgNumeric::from_bytes(bytes)? {
+            PgNumeric::Number(x) => Ok(x),
+            _ => Err(MathErrors::DomainError),
+        };
+    }
+}
+
+#[cfg(feature = "postgres-types")]
+mod sql {
+    // External imports
+    use bytes::BytesMut;
+    use postgres_types::to_sql_checked;
+    use postgres_types::FromSql;
+    use postgres_types::IsNull;
+    use postgres_types::ToSql;
+    use postgres_types::Type;
+    use std::error::Error;
+
+    // Inter crate imports
+    use super::BigNumber;
+    use super::PgNumeric;
+
+    impl ToSql for PgNumeric {
+        fn to_sql(
+            &self,
+            _: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            out.extend_from_slice(&self.to_bytes()?);
+            return Ok(IsNull::No);
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            return *ty == Type::NUMERIC;
+        }
+
+        to_sql_checked!();
+    }
+
+    impl<'a> FromSql<'a> for PgNumeric {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            return Ok(PgNumeric::from_bytes(raw)?);
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            return *ty == Type::NUMERIC;
+        }
+    }
+
+    impl ToSql for BigNumber {
+        fn to_sql(
+            &self,
+            _: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            out.extend_from_slice(&self.to_pg_numeric()?);
+            return Ok(IsNull::No);
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            return *ty == Type::NUMERIC;
+        }
+
+        to_sql_checked!();
+    }
+
+    /// NaN and the infinities are rejected, read a [PgNumeric] to get them.
+    impl<'a> FromSql<'a> for BigNumber {
+        fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            return Ok(BigNumber::from_pg_numeric(raw)?);
+        }
+
+        fn accepts(ty: &Type) -> bool {
+            return *ty == Type::NUMERIC;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_pg_numeric_fixtures() {
+        // (value, bytes as numeric_send writes them)
+        let test_data: [(&str, &[u8]); 11] = [
+            ("0", &[0, 0, 0, 0, 0, 0, 0, 0]),
+            ("0.00", &[0, 0, 0, 0, 0, 0, 0, 2]),
+            ("1", &[0, 1, 0, 0, 0, 0, 0, 0, 0, 1]),
+            ("10000", &[0, 1, 0, 1, 0, 0, 0, 0, 0, 1]),
+            ("-1", &[0, 1, 0, 0, 0x40, 0, 0, 0, 0, 1]),
+            ("123.45", &[0, 2, 0, 0, 0, 0, 0, 2, 0, 0x7B, 0x11, 0x94]),
+            ("-0.0001", &[0, 1, 0xFF, 0xFF, 0x40, 0, 0, 4, 0, 1]),
+            ("0.00000001", &[0, 1, 0xFF, 0xFE, 0, 0, 0, 8, 0, 1]),
+            (
+                "12345678.9",
+                &[0, 3, 0, 1, 0, 0, 0, 1, 0x04, 0xD2, 0x16, 0x2E, 0x23, 0x28],
+            ),
+            ("1.50", &[0, 2, 0, 0, 0, 0, 0, 2, 0, 1, 0x13, 0x88]),
+            ("100000000.000", &[0, 1, 0, 2, 0, 0, 0, 3, 0, 1]),
+        ];
+        for (value, bytes) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_pg_numeric().unwrap(), bytes);
+            let decoded = BigNumber::from_pg_numeric(bytes).unwrap();
+            assert_eq!(decoded.to_string(), value);
+        }
+    }
+
+    #[test]
+    fn test_pg_numeric_special_values() {
+        let test_data: [(PgNumeric, &[u8]); 3] = [
+            (PgNumeric::NaN, &[0, 0, 0, 0, 0xC0, 0, 0, 0]),
+            (PgNumeric::Infinity, &[0, 0, 0, 0, 0xD0, 0, 0, 0]),
+            (PgNumeric::NegativeInfinity, &[0, 0, 0, 0, 0xF0, 0, 0, 0]),
+        ];
+        for (value, bytes) in test_data {
+            assert_eq!(value.to_bytes().unwrap(), bytes);
+            assert_eq!(PgNumeric::from_bytes(bytes).unwrap(), value);
+            assert_eq!(
+                BigNumber::from_pg_numeric(bytes),
+                Err(MathErrors::DomainError)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pg_numeric_invalid() {
+        let test_data: [&[u8]; 5] = [
+            &[0, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 0, 0, 0, 0, 0, 0, 0x27, 0x10],
+            &[0, 0, 0, 0, 0x80, 0, 0, 0],
+            &[0, 0, 0, 0, 0, 0, 0x40, 0],
+        ];
+        for bytes in test_data {
+            assert_eq!(PgNumeric::from_bytes(bytes), Err(MathErrors::ParseError));
+        }
+        // Digits beyond the display scale are kept rather than lost.
+        let bytes: &[u8] = &[0, 1, 0xFF, 0xFF, 0, 0, 0, 2, 0x04, 0xD2];
+        assert_eq!(
+            BigNumber::from_pg_numeric(bytes).unwrap().to_string(),
+            "0.1234"
+        );
+        let wide = BigNumber::from_str("1").unwrap().shift_decimal(-16384);
+        assert_eq!(wide.to_pg_numeric(), Err(MathErrors::Overflow));
+    }
+
+    #[cfg(feature = "postgres-types")]
+    #[test]
+    fn test_pg_numeric_sql() {
+        use bytes::BytesMut;
+        use postgres_types::FromSql;
+        use postgres_types::ToSql;
+        use postgres_types::Type;
+
+        let x = BigNumber::from_str("-123.45").unwrap();
+        let mut out = BytesMut::new();
+        x.to_sql_checked(&Type::NUMERIC, &mut out).unwrap();
+        assert_eq!(&out[..], &[0, 2, 0, 0, 0x40, 0, 0, 2, 0, 0x7B, 0x11, 0x94]);
+        assert!(x.to_sql_checked(&Type::INT4, &mut out).is_err());
+        assert_eq!(BigNumber::from_sql(&Type::NUMERIC, &out).unwrap(), x);
+        let nan: &[u8] = &[0, 0, 0, 0, 0xC0, 0, 0, 0];
+        assert!(BigNumber::from_sql(&Type::NUMERIC, nan).is_err());
+        assert_eq!(
+            PgNumeric::from_sql(&Type::NUMERIC, nan).unwrap(),
+            PgNumeric::NaN
+        );
+    }
+}
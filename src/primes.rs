@@ -0,0 +1,389 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::magnitude;
+use super::number_theory;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// Primes below this are removed by trial division before anything else.
+const TRIAL_DIVISION_LIMIT: u32 = 10_000;
+
+/// Miller-Rabin with the first twelve primes as bases is exact below
+/// 318665857834031151167461 (Sorenson and Webster) which covers every u64.
+const DETERMINISTIC_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Adding 41 as a thirteenth base keeps the test exact up to this bound.
+const DETERMINISTIC_LIMIT: u128 = 3317044064679887385961981;
+
+/// Returns the primes below limit using the sieve of Eratosthenes.
+fn small_primes(limit: u32) -> Vec<u32> {
+    let mut composite: Vec<bool> = vec![false; limit as usize];
+    let mut primes: Vec<u32> = Vec::new();
+    for n in 2..limit {
+        if composite[n as usize] {
+            continue;
+        }
+        primes.push(n);
+        for multiple in (n as usize * n as usize..limit as usize).step_by(n as usize) {
+            composite[multiple] = true;
+        }
+    }
+    return primes;
+}
+
+/// A xorshift generator, used to pick Miller-Rabin bases and Pollard rho
+/// constants reproducibly without another dependency.
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    return *state;
+}
+
+fn multiply_mod_u64(x: u64, y: u64, m: u64) -> u64 {
+    return ((x as u128 * y as u128) % m as u128) as u64;
+}
+
+fn pow_mod_u64(base: u64, exponent: u64, m: u64) -> u64 {
+    let mut result: u64 = 1 % m;
+    let mut base: u64 = base % m;
+    let mut exponent: u64 = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply_mod_u64(result, base, m);
+        }
+        base = multiply_mod_u64(base, base, m);
+        exponent >>= 1;
+    }
+    return result;
+}
+
+fn gcd_u64(x: u64, y: u64) -> u64 {
+    let (mut a, mut b) = (x, y);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    return a;
+}
+
+/// Returns true if n is prime, exactly, for an odd n above the trial
+/// division limit.
+fn is_prime_u64(n: u64) -> bool {
+    let s: u32 = (n - 1).trailing_zeros();
+    let d: u64 = (n - 1) >> s;
+    'bases: for base in DETERMINISTIC_BASES {
+        let mut x: u64 = pow_mod_u64(base, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = multiply_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    return true;
+}
+
+/// Returns a nontrivial factor of the odd composite n using Brent's variant
+/// of Pollard's rho.
+fn rho_u64(n: u64) -> u64 {
+    let mut state: u64 = n | 1;
+    loop {
+        let c: u64 = next_random(&mut state) % (n - 1) + 1;
+        let f = |x: u64| ((multiply_mod_u64(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let mut y: u64 = next_random(&mut state) % n;
+        let mut x: u64 = y;
+        let mut saved: u64 = y;
+        let mut g: u64 = 1;
+        let mut r: u64 = 1;
+        let mut q: u64 = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k: u64 = 0;
+            while k < r && g == 1 {
+                saved = y;
+                for _ in 0..(r - k).min(128) {
+                    y = f(y);
+                    q = multiply_mod_u64(q, x.abs_diff(y), n);
+                }
+                g = gcd_u64(q, n);
+                k += 128;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // The batched product skipped past the factor, so retrace the
+            // last batch one step at a time.
+            loop {
+                saved = f(saved);
+                g = gcd_u64(x.abs_diff(saved), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+}
+
+/// Returns |x - y|.
+fn distance(x: &[Digits], y: &[Digits]) -> Vec<Digits> {
+    if magnitude::compare(x, y) == Ordering::Less {
+        return magnitude::subtract(y, x);
+    }
+    return magnitude::subtract(x, y);
+}
+
+/// Returns true if n passes a Miller-Rabin round for every base, given
+/// n - 1 = d 2^s.
+fn miller_rabin(n: &[Digits], d: &[Digits], s: usize, bases: &[Vec<Digits>]) -> bool {
+    let one: Vec<Digits> = vec![Digits::One];
+    let n_minus_one: Vec<Digits> = magnitude::subtract(n, &one);
+    'bases: for base in bases {
+        let mut x: Vec<Digits> = number_theory::pow_mod(base, d, n);
+        if x == one || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = number_theory::multiply_mod(&x, &x, n);
+            if x == n_minus_one {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    return true;
+}
+
+/// Returns true if n is probably prime for an odd n above the trial division
+/// limit. The answer is exact below [DETERMINISTIC_LIMIT] and otherwise wrong
+/// for a composite with probability at most 4^-rounds.
+fn is_probable_prime_magnitude(n: &[Digits], rounds: usize) -> bool {
+    if let Some(small) = magnitude::to_u128(n) {
+        if small <= u64::MAX as u128 {
+            return is_prime_u64(small as u64);
+        }
+    }
+    let mut d: Vec<Digits> = magnitude::subtract(n, &[Digits::One]);
+    let mut s: usize = 0;
+    loop {
+        let (half, remainder) = magnitude::divide_small(&d, 2).unwrap();
+        if remainder != 0 {
+            break;
+        }
+        d = half;
+        s += 1;
+    }
+    let mut bases: Vec<Vec<Digits>> = DETERMINISTIC_BASES
+        .iter()
+        .map(|b| magnitude::from_u128(*b as u128))
+        .collect();
+    bases.push(magnitude::from_u128(41));
+    if magnitude::to_u128(n).is_some_and(|small| small < DETERMINISTIC_LIMIT) {
+        return miller_rabin(n, &d, s, &bases);
+    }
+    // Larger values get the fixed bases followed by pseudorandom ones, which
+    // are all below n since n does not fit in a u64.
+    let mut state: u64 = magnitude::to_u128(&n[n.len() - 18..]).unwrap() as u64 | 1;
+    for _ in 0..rounds.saturating_sub(bases.len()) {
+        let base: u64 = next_random(&mut state).max(2);
+        bases.push(magnitude::from_u128(base as u128));
+    }
+    return miller_rabin(n, &d, s, &bases);
+}
+
+/// Returns a nontrivial factor of the odd composite n using Brent's variant
+/// of Pollard's rho.
+fn rho_magnitude(n: &[Digits]) -> Vec<Digits> {
+    let mut state: u64 = magnitude::to_u128(&n[n.len() - 18..]).unwrap() as u64 | 1;
+    loop {
+        let c: Vec<Digits> = magnitude::from_u128(next_random(&mut state) as u128);
+        let f = |x: &[Digits]| {
+            number_theory::remainder(&magnitude::add(&magnitude::multiply(x, x), &c), n)
+        };
+        let mut y: Vec<Digits> = magnitude::from_u128(next_random(&mut state) as u128);
+        let mut x: Vec<Digits> = y.clone();
+        let mut saved: Vec<Digits> = y.clone();
+        let mut g: Vec<Digits> = vec![Digits::One];
+        let mut r: usize = 1;
+        let mut q: Vec<Digits> = vec![Digits::One];
+        while g == [Digits::One] {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k: usize = 0;
+            while k < r && g == [Digits::One] {
+                saved = y.clone();
+                for _ in 0..(r - k).min(128) {
+                    y = f(&y);
+                    q = number_theory::multiply_mod(&q, &distance(&x, &y), n);
+                }
+                g = number_theory::gcd(&q, n);
+                k += 128;
+            }
+            r *= 2;
+        }
+        if g == n {
+            loop {
+                saved = f(&saved);
+                g = number_theory::gcd(&distance(&x, &saved), n);
+                if g != [Digits::One] {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+}
+
+/// Appends the prime factors of n, which has no factors below the trial
+/// division limit, to factors.
+fn factor_into(n: Vec<Digits>, factors: &mut Vec<Vec<Digits>>) {
+    if n == [Digits::One] {
+        return;
+    }
+    if let Some(small) = magnitude::to_u128(&n) {
+        if small <= u64::MAX as u128 {
+            let small: u64 = small as u64;
+            if is_prime_u64(small) {
+                factors.push(n);
+                return;
+            }
+            let divisor: u64 = rho_u64(small);
+            factor_into(magnitude::from_u128(divisor as u128), factors);
+            factor_into(magnitude::from_u128((small / divisor) as u128), factors);
+            return;
+        }
+    }
+    if is_probable_prime_magnitude(&n, 32) {
+        factors.push(n);
+        return;
+    }
+    let divisor: Vec<Digits> = rho_magnitude(&n);
+    let (cofactor, _) = magnitude::divide(&n, &divisor).unwrap();
+    factor_into(divisor, factors);
+    factor_into(cofactor, factors);
+}
+
+impl BigNumber {
+    /// Returns true if self is a prime number.
+    ///
+    /// Trial division is followed by Miller-Rabin, which is exact below
+    /// 3317044064679887385961981. Above that, a composite is reported as
+    /// prime with probability at most 4^-rounds. Values below two are never
+    /// prime.
+    ///
+    /// [MathErrors::DomainError] is returned if self is not an integer.
+    pub fn is_probable_prime(self: &Self, rounds: usize) -> Result<bool, MathErrors> {
+        let n: Vec<Digits> = self.integral()?;
+        if self.is_negative() || magnitude::compare(&n, &[Digits::Two]) == Ordering::Less {
+            return Ok(false);
+        }
+        for p in small_primes(TRIAL_DIVISION_LIMIT) {
+            let (_, remainder) = magnitude::divide_small(&n, p)?;
+            if remainder == 0 {
+                return Ok(magnitude::to_u128(&n) == Some(p as u128));
+            }
+        }
+        if magnitude::to_u128(&n).is_some_and(|small| small < (TRIAL_DIVISION_LIMIT as u128).pow(2))
+        {
+            return Ok(true);
+        }
+        return Ok(is_probable_prime_magnitude(&n, rounds));
+    }
+
+    /// Returns the prime factors of the absolute value of self in ascending
+    /// order, each repeated as often as it divides self.
+    ///
+    /// One has no prime factors. [MathErrors::DomainError] is returned if
+    /// self is zero or not an integer.
+    pub fn factorize(self: &Self) -> Result<Vec<BigNumber>, MathErrors> {
+        let mut n: Vec<Digits> = self.integral()?;
+        if n.is_empty() {
+            return Err(MathErrors::DomainError);
+        }
+        let mut factors: Vec<Vec<Digits>> = Vec::new();
+        for p in small_primes(TRIAL_DIVISION_LIMIT) {
+            loop {
+                let (quotient, remainder) = magnitude::divide_small(&n, p)?;
+                if remainder != 0 {
+                    break;
+                }
+                factors.push(magnitude::from_u128(p as u128));
+                n = quotient;
+            }
+        }
+        factor_into(n, &mut factors);
+        factors.sort_by(|x, y| magnitude::compare(x, y));
+        return Ok(factors
+            .into_iter()
+            .map(|f| BigNumber::from_coefficient(Sign::Positive, f, 0))
+            .collect());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_is_probable_prime() {
+        let test_data = [
+            ("0", Ok(false)),
+            ("1", Ok(false)),
+            ("2", Ok(true)),
+            ("-7", Ok(false)),
+            ("561", Ok(false)),
+            ("9973", Ok(true)),
+            ("1000000007", Ok(true)),
+            ("3825123056546413051", Ok(false)),
+            ("2305843009213693951", Ok(true)),
+            ("618970019642690137449562111", Ok(true)),
+            ("618970019642690137449562113", Ok(false)),
+            ("7.5", Err(MathErrors::DomainError)),
+        ];
+        for (n, expected) in test_data {
+            let n = BigNumber::from_str(n).unwrap();
+            assert_eq!(n.is_probable_prime(20), expected);
+        }
+    }
+
+    #[test]
+    fn test_factorize() {
+        let test_data: [(&str, Result<&[&str], MathErrors>); 6] = [
+            ("1", Ok(&[])),
+            ("360", Ok(&["2", "2", "2", "3", "3", "5"])),
+            ("-91", Ok(&["7", "13"])),
+            ("600851475143", Ok(&["71", "839", "1471", "6857"])),
+            ("18446744073709551617", Ok(&["274177", "67280421310721"])),
+            ("0", Err(MathErrors::DomainError)),
+        ];
+        for (n, expected) in test_data {
+            let n = BigNumber::from_str(n).unwrap();
+            let expected = expected.map(|factors| {
+                factors
+                    .iter()
+                    .map(|f| BigNumber::from_str(f).unwrap())
+                    .collect::<Vec<BigNumber>>()
+            });
+            assert_eq!(n.factorize(), expected);
+        }
+    }
+}
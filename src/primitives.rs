@@ -0,0 +1,213 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp::Ordering;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Sub;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Sign;
+use super::DIVISION_PRECISION;
+
+impl BigNumber {
+    /// Divides self by rhs the way the `/` operator does, rounding to
+    /// [DIVISION_PRECISION] significant digits and dropping trailing zeros.
+    ///
+    /// Panics if rhs is zero, just like integer division.
+    pub(crate) fn operator_quotient(self: &Self, rhs: &BigNumber) -> BigNumber {
+        if rhs.is_zero() {
+            panic!("attempt to divide a BigNumber by zero");
+        }
+        let mut result: BigNumber = self.quotient_to_precision(rhs, DIVISION_PRECISION).unwrap();
+        result.normalize();
+        return result;
+    }
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigNumber {
+            fn from(input: $t) -> Self {
+                return BigNumber::from_integer(input as i128);
+            }
+        }
+    )*};
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigNumber {
+            fn from(input: $t) -> Self {
+                return BigNumber::from_coefficient(
+                    Sign::Positive,
+                    magnitude::from_u128(input as u128),
+                    0,
+                );
+            }
+        }
+    )*};
+}
+
+// Each integer type can be used on either side of the arithmetic operators
+// and compared against a BigNumber without an explicit conversion.
+macro_rules! mixed_operators {
+    ($($t:ty),*) => {$(
+        impl Add<$t> for BigNumber {
+            type Output = BigNumber;
+
+            fn add(self, rhs: $t) -> BigNumber {
+                return self.sum(&BigNumber::from(rhs));
+            }
+        }
+
+        impl Add<BigNumber> for $t {
+            type Output = BigNumber;
+
+            fn add(self, rhs: BigNumber) -> BigNumber {
+                return BigNumber::from(self).sum(&rhs);
+            }
+        }
+
+        impl Sub<$t> for BigNumber {
+            type Output = BigNumber;
+
+            fn sub(self, rhs: $t) -> BigNumber {
+                return self.difference(&BigNumber::from(rhs));
+            }
+        }
+
+        impl Sub<BigNumber> for $t {
+            type Output = BigNumber;
+
+            fn sub(self, rhs: BigNumber) -> BigNumber {
+                return BigNumber::from(self).difference(&rhs);
+            }
+        }
+
+        impl Mul<$t> for BigNumber {
+            type Output = BigNumber;
+
+            fn mul(self, rhs: $t) -> BigNumber {
+                return self.product(&BigNumber::from(rhs));
+            }
+        }
+
+        impl Mul<BigNumber> for $t {
+            type Output = BigNumber;
+
+            fn mul(self, rhs: BigNumber) -> BigNumber {
+                return BigNumber::from(self).product(&rhs);
+            }
+        }
+
+        impl Div<$t> for BigNumber {
+            type Output = BigNumber;
+
+            /// Panics if rhs is zero. See [BigNumber::operator_quotient].
+            fn div(self, rhs: $t) -> BigNumber {
+                return self.operator_quotient(&BigNumber::from(rhs));
+            }
+        }
+
+        impl Div<BigNumber> for $t {
+            type Output = BigNumber;
+
+            /// Panics if rhs is zero. See [BigNumber::operator_quotient].
+            fn div(self, rhs: BigNumber) -> BigNumber {
+                return BigNumber::from(self).operator_quotient(&rhs);
+            }
+        }
+
+        impl PartialEq<$t> for BigNumber {
+            fn eq(&self, other: &$t) -> bool {
+                return self.cmp(&BigNumber::from(*other)) == Ordering::Equal;
+            }
+        }
+
+        impl PartialEq<BigNumber> for $t {
+            fn eq(&self, other: &BigNumber) -> bool {
+                return other == self;
+            }
+        }
+
+        impl PartialOrd<$t> for BigNumber {
+            fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                return Some(self.cmp(&BigNumber::from(*other)));
+            }
+        }
+
+        impl PartialOrd<BigNumber> for $t {
+            fn partial_cmp(&self, other: &BigNumber) -> Option<Ordering> {
+                return Some(BigNumber::from(*self).cmp(other));
+            }
+        }
+    )*};
+}
+
+from_signed!(i8, i16, i32, i64, i128, isize);
+from_unsigned!(u8, u16, u32, u64, usize);
+mixed_operators!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_mixed_arithmetic() {
+        let price = BigNumber::from_str("19.99").unwrap();
+        let test_data = [
+            (price.clone() + 1i64, "20.99"),
+            (1i64 + price.clone(), "20.99"),
+            (price.clone() - 20i32, "-0.01"),
+            (20u8 - price.clone(), "0.01"),
+            (price.clone() * 3, "59.97"),
+            (-3i64 * price.clone(), "-59.97"),
+            (price.clone() / 4u64, "4.9975"),
+            (1 / BigNumber::from_str("8").unwrap(), "0.125"),
+            (
+                2 / BigNumber::from_str("3").unwrap(),
+                "0.6666666666666666666666666666666667",
+            ),
+            (
+                BigNumber::from_str("-7.5").unwrap() + u128::MAX,
+                "340282366920938463463374607431768211447.5",
+            ),
+            (
+                BigNumber::from_str("-7.5").unwrap() + i128::MIN,
+                "-170141183460469231731687303715884105735.5",
+            ),
+        ];
+        for (result, expected) in test_data {
+            assert_eq!(result, BigNumber::from_str(expected).unwrap());
+        }
+        let quarter: BigNumber = price / 4;
+        assert_eq!(quarter.to_string(), "4.9975");
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide a BigNumber by zero")]
+    fn test_mixed_division_by_zero() {
+        let _ = BigNumber::from_str("1.5").unwrap() / 0;
+    }
+
+    #[test]
+    fn test_mixed_comparisons() {
+        let balance = BigNumber::from_str("-0.01").unwrap();
+        assert!(balance < 0);
+        assert!(0 > balance);
+        assert!(balance > -1i64);
+        assert!(balance != 0u32);
+        assert!(BigNumber::from_str("0.000").unwrap() == 0);
+        assert!(BigNumber::from_str("42.0").unwrap() == 42usize);
+        assert!(42i8 == BigNumber::from_str("42").unwrap());
+        assert!(BigNumber::from_str("42.5").unwrap() >= 42);
+        assert_eq!(
+            BigNumber::from_str("255").unwrap().partial_cmp(&255u8),
+            Some(Ordering::Equal)
+        );
+    }
+}
@@ -0,0 +1,215 @@
+#![warn(dead_code)]
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+
+/// Yields start, start + step, start + 2 step, … for a fixed number of
+/// values.
+///
+/// Every value is computed directly from start and its index, so no error
+/// builds up however long the sequence is. Created by [BigNumber::range],
+/// [BigNumber::range_inclusive] and [BigNumber::range_count].
+#[derive(Clone, Debug)]
+pub struct DecimalRange {
+    start: BigNumber,
+    step: BigNumber,
+    front: usize,
+    back: usize,
+}
+
+impl DecimalRange {
+    fn value(self: &Self, index: usize) -> BigNumber {
+        return self.start.sum(&self.step.product(&BigNumber::from(index)));
+    }
+}
+
+impl Iterator for DecimalRange {
+    type Item = BigNumber;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let result: BigNumber = self.value(self.front);
+        self.front += 1;
+        return Some(result);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length: usize = self.back - self.front;
+        return (length, Some(length));
+    }
+}
+
+impl DoubleEndedIterator for DecimalRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        return Some(self.value(self.back));
+    }
+}
+
+impl ExactSizeIterator for DecimalRange {}
+
+impl BigNumber {
+    /// Returns the values from start up to but not including end, spaced
+    /// step apart.
+    ///
+    /// A negative step counts down. If step points away from end the range
+    /// is empty. [MathErrors::DomainError] is returned if step is zero or
+    /// the range has more than usize::MAX values.
+    pub fn range(
+        start: &BigNumber,
+        end: &BigNumber,
+        step: &BigNumber,
+    ) -> Result<DecimalRange, MathErrors> {
+        let count: usize = BigNumber::range_length(start, end, step, false)?;
+        return Ok(BigNumber::range_count(start, step, count));
+    }
+
+    /// Returns the values from start up to and including end, spaced step
+    /// apart. End is only included if it is a whole number of steps from
+    /// start.
+    ///
+    /// A negative step counts down. If step points away from end the range
+    /// is empty. [MathErrors::DomainError] is returned if step is zero or
+    /// the range has more than usize::MAX values.
+    pub fn range_inclusive(
+        start: &BigNumber,
+        end: &BigNumber,
+        step: &BigNumber,
+    ) -> Result<DecimalRange, MathErrors> {
+        let count: usize = BigNumber::range_length(start, end, step, true)?;
+        return Ok(BigNumber::range_count(start, step, count));
+    }
+
+    /// Returns exactly count values starting at start and spaced step apart.
+    pub fn range_count(start: &BigNumber, step: &BigNumber, count: usize) -> DecimalRange {
+        return DecimalRange {
+            start: start.clone(),
+            step: step.clone(),
+            front: 0,
+            back: count,
+        };
+    }
+
+    /// Counts the values between start and end that are a whole number of
+    /// steps from start.
+    fn range_length(
+        start: &BigNumber,
+        end: &BigNumber,
+        step: &BigNumber,
+        inclusive: bool,
+    ) -> Result<usize, MathErrors> {
+        if step.is_zero() {
+            return Err(MathErrors::DomainError);
+        }
+        let distance: BigNumber = end.difference(start);
+        if distance.is_zero() {
+            return Ok(if inclusive { 1 } else { 0 });
+        }
+        if !distance.is_the_same_sign_as(step) {
+            return Ok(0);
+        }
+        let (x, y, _) = BigNumber::aligned_coefficients(&distance, step);
+        let (quotient, remainder) = magnitude::divide(&x, &y)?;
+        // The last value short of end is quotient steps along. It is only
+        // excluded when it lands exactly on end of an exclusive range.
+        let mut count: Vec<Digits> = quotient;
+        if inclusive || !magnitude::is_zero(&remainder) {
+            count = magnitude::add(&count, &[Digits::One]);
+        }
+        return match magnitude::to_u128(&count) {
+            Some(n) if n <= usize::MAX as u128 => Ok(n as usize),
+            _ => Err(MathErrors::DomainError),
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_range() {
+        // (start, end, step, inclusive, expected)
+        let test_data = [
+            (
+                "0.0",
+                "1",
+                "0.1",
+                false,
+                "0.0 0.1 0.2 0.3 0.4 0.5 0.6 0.7 0.8 0.9",
+            ),
+            (
+                "0.0",
+                "1",
+                "0.1",
+                true,
+                "0.0 0.1 0.2 0.3 0.4 0.5 0.6 0.7 0.8 0.9 1.0",
+            ),
+            ("1", "0", "-0.25", false, "1.00 0.75 0.50 0.25"),
+            ("1", "0", "-0.25", true, "1.00 0.75 0.50 0.25 0.00"),
+            ("9.99", "10.5", "0.2", true, "9.99 10.19 10.39"),
+            ("9.99", "10.39", "0.2", false, "9.99 10.19"),
+            ("-1", "1", "0.75", false, "-1.00 -0.25 0.50"),
+            ("0", "1", "-0.1", true, ""),
+            ("1", "0", "0.1", false, ""),
+            ("5", "5", "1", false, ""),
+            ("5", "5", "1", true, "5"),
+        ];
+        for (start, end, step, inclusive, expected) in test_data {
+            let start = BigNumber::from_str(start).unwrap();
+            let end = BigNumber::from_str(end).unwrap();
+            let step = BigNumber::from_str(step).unwrap();
+            let range: DecimalRange = if inclusive {
+                BigNumber::range_inclusive(&start, &end, &step).unwrap()
+            } else {
+                BigNumber::range(&start, &end, &step).unwrap()
+            };
+            let values: Vec<String> = range.map(|x| x.to_string()).collect();
+            assert_eq!(values.join(" "), expected);
+        }
+    }
+
+    #[test]
+    fn test_range_count() {
+        let start = BigNumber::from_str("0.1").unwrap();
+        let step = BigNumber::from_str("0.2").unwrap();
+        let mut range: DecimalRange = BigNumber::range_count(&start, &step, 4);
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.next_back(), Some(BigNumber::from_str("0.7").unwrap()));
+        assert_eq!(range.next(), Some(BigNumber::from_str("0.1").unwrap()));
+        assert_eq!(range.len(), 2);
+        let rest: Vec<String> = range.map(|x| x.to_string()).collect();
+        assert_eq!(rest, vec!["0.3", "0.5"]);
+
+        // A long range lands exactly where it should.
+        let tenth = BigNumber::from_str("0.1").unwrap();
+        let mut range = BigNumber::range_count(&BigNumber::zero(), &tenth, 1_000_001);
+        assert_eq!(
+            range.next_back(),
+            Some(BigNumber::from_str("100000").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_range_errors() {
+        let one = BigNumber::from_str("1").unwrap();
+        let zero = BigNumber::from_str("0.0").unwrap();
+        assert_eq!(
+            BigNumber::range(&zero, &one, &zero).unwrap_err(),
+            MathErrors::DomainError
+        );
+        let huge = BigNumber::from_str("1000000000000000000000000000000").unwrap();
+        assert_eq!(
+            BigNumber::range_inclusive(&zero, &huge, &one).unwrap_err(),
+            MathErrors::DomainError
+        );
+    }
+}
@@ -0,0 +1,161 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::MathErrors;
+use super::Sign;
+
+impl BigNumber {
+    /// Returns the remainder of dividing self by rhs with the quotient
+    /// truncated toward zero, so the result has the sign of self.
+    ///
+    /// 7.5 rem_trunc 2 is 1.5 and -7.5 rem_trunc 2 is -1.5.
+    /// [MathErrors::DivisionByZero] is returned if rhs is zero.
+    pub fn rem_trunc(self: &Self, rhs: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let (_, remainder) = self.truncated_division(rhs)?;
+        return Ok(remainder);
+    }
+
+    /// Returns the remainder of dividing self by rhs with the quotient
+    /// rounded toward negative infinity, so the result has the sign of rhs.
+    ///
+    /// -7.5 rem_floor 2 is 0.5 and 7.5 rem_floor -2 is -0.5.
+    /// [MathErrors::DivisionByZero] is returned if rhs is zero.
+    pub fn rem_floor(self: &Self, rhs: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let (_, remainder) = self.truncated_division(rhs)?;
+        if !remainder.is_zero() && !remainder.is_the_same_sign_as(rhs) {
+            return Ok(remainder.sum(rhs));
+        }
+        return Ok(remainder);
+    }
+
+    /// Returns the remainder of the Euclidean division of self by rhs, which
+    /// is never negative and is less than |rhs|.
+    ///
+    /// -7.5 rem_euclid 2 and -7.5 rem_euclid -2 are both 0.5.
+    /// [MathErrors::DivisionByZero] is returned if rhs is zero.
+    pub fn rem_euclid(self: &Self, rhs: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let (_, remainder) = self.truncated_division(rhs)?;
+        if remainder.is_negative() {
+            return Ok(remainder.sum(&rhs.abs()));
+        }
+        return Ok(remainder);
+    }
+
+    /// Returns the integer quotient q of the Euclidean division of self by
+    /// rhs, so that self = q rhs + self.rem_euclid(rhs).
+    ///
+    /// [MathErrors::DivisionByZero] is returned if rhs is zero.
+    pub fn div_euclid(self: &Self, rhs: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let (quotient, remainder) = self.truncated_division(rhs)?;
+        if !remainder.is_negative() {
+            return Ok(quotient);
+        }
+        let one: BigNumber = BigNumber::one();
+        if rhs.is_negative() {
+            return Ok(quotient.sum(&one));
+        }
+        return Ok(quotient.difference(&one));
+    }
+
+    /// The remainder operation of the General Decimal Arithmetic
+    /// Specification, which is the same as [BigNumber::rem_trunc].
+    ///
+    /// [MathErrors::DivisionByZero] is returned if rhs is zero.
+    pub fn remainder(self: &Self, rhs: &BigNumber) -> Result<BigNumber, MathErrors> {
+        return self.rem_trunc(rhs);
+    }
+
+    /// The remainder-near operation of the General Decimal Arithmetic
+    /// Specification, self - n rhs where n is self / rhs rounded to the
+    /// nearest integer with ties going to the even integer.
+    ///
+    /// 10 remainder_near 6 is -2 and 10 remainder_near 4 is 2.
+    /// [MathErrors::DivisionByZero] is returned if rhs is zero.
+    pub fn remainder_near(self: &Self, rhs: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let (quotient, remainder) = self.truncated_division(rhs)?;
+        let divisor: BigNumber = rhs.abs();
+        let twice: BigNumber = remainder.abs().product(&BigNumber::from_integer(2));
+        let away: bool = match twice.cmp(&divisor) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => quotient
+                .integer
+                .last()
+                .is_some_and(|d| d.to_usize() % 2 == 1),
+        };
+        if !away {
+            return Ok(remainder);
+        }
+        if remainder.is_negative() {
+            return Ok(remainder.sum(&divisor));
+        }
+        return Ok(remainder.difference(&divisor));
+    }
+
+    /// Divides self by rhs exactly, returning the quotient truncated to an
+    /// integer and the remainder, which has the sign of self.
+    fn truncated_division(
+        self: &Self,
+        rhs: &BigNumber,
+    ) -> Result<(BigNumber, BigNumber), MathErrors> {
+        let (x, y, scale) = BigNumber::aligned_coefficients(self, rhs);
+        let (quotient, remainder) = magnitude::divide(&x, &y)?;
+        let sign: Sign = if self.is_the_same_sign_as(rhs) {
+            Sign::Positive
+        } else {
+            Sign::Negative
+        };
+        return Ok((
+            BigNumber::from_coefficient(sign, quotient, 0),
+            BigNumber::from_coefficient(self.sign, remainder, scale),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_remainders() {
+        // (x, y, rem_trunc, rem_floor, rem_euclid, div_euclid, remainder_near)
+        let test_data = [
+            ("7.5", "2", "1.5", "1.5", "1.5", "3", "-0.5"),
+            ("-7.5", "2", "-1.5", "0.5", "0.5", "-4", "0.5"),
+            ("7.5", "-2", "1.5", "-0.5", "1.5", "-3", "-0.5"),
+            ("-7.5", "-2", "-1.5", "-1.5", "0.5", "4", "0.5"),
+            ("10", "4", "2", "2", "2", "2", "2"),
+            ("10", "6", "4", "4", "4", "1", "-2"),
+            ("6", "3", "0", "0", "0", "2", "0"),
+            ("-6", "3", "0", "0", "0", "-2", "0"),
+            ("0.1", "0.03", "0.01", "0.01", "0.01", "3", "0.01"),
+            ("1.25", "10", "1.25", "1.25", "1.25", "0", "1.25"),
+        ];
+        for (x, y, trunc, floor, euclid, quotient, near) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let y = BigNumber::from_str(y).unwrap();
+            assert_eq!(x.rem_trunc(&y), Ok(BigNumber::from_str(trunc).unwrap()));
+            assert_eq!(x.remainder(&y), Ok(BigNumber::from_str(trunc).unwrap()));
+            assert_eq!(x.rem_floor(&y), Ok(BigNumber::from_str(floor).unwrap()));
+            assert_eq!(x.rem_euclid(&y), Ok(BigNumber::from_str(euclid).unwrap()));
+            assert_eq!(x.div_euclid(&y), Ok(BigNumber::from_str(quotient).unwrap()));
+            assert_eq!(x.remainder_near(&y), Ok(BigNumber::from_str(near).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_remainder_by_zero() {
+        let x = BigNumber::from_str("7.5").unwrap();
+        let zero = BigNumber::from_str("0.00").unwrap();
+        assert_eq!(x.rem_trunc(&zero), Err(MathErrors::DivisionByZero));
+        assert_eq!(x.rem_floor(&zero), Err(MathErrors::DivisionByZero));
+        assert_eq!(x.rem_euclid(&zero), Err(MathErrors::DivisionByZero));
+        assert_eq!(x.div_euclid(&zero), Err(MathErrors::DivisionByZero));
+        assert_eq!(x.remainder_near(&zero), Err(MathErrors::DivisionByZero));
+    }
+}
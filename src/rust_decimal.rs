@@ -0,0 +1,149 @@
+#![warn(dead_code)]
+// External imports
+use ::rust_decimal::Decimal;
+use std::cmp::Ordering;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+// The largest coefficient a Decimal can hold, 2^96 - 1.
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+impl From<Decimal> for BigNumber {
+    /// Keeps every digit and the scale, so 1.50 stays 1.50.
+    fn from(input: Decimal) -> Self {
+        let mantissa: i128 = input.mantissa();
+        let sign: Sign = if mantissa < 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        return BigNumber::from_coefficient(
+            sign,
+            magnitude::from_u128(mantissa.unsigned_abs()),
+            input.scale() as usize,
+        );
+    }
+}
+
+impl From<&Decimal> for BigNumber {
+    /// Keeps every digit and the scale, so 1.50 stays 1.50.
+    fn from(input: &Decimal) -> Self {
+        return BigNumber::from(*input);
+    }
+}
+
+impl TryFrom<&BigNumber> for Decimal {
+    type Error = MathErrors;
+
+    /// Trailing zeros in the decimal portion are dropped if that is what it
+    /// takes to fit, nothing else is.
+    ///
+    /// [MathErrors::Overflow] is returned if the integer portion is larger
+    /// than [Decimal::MAX] and [MathErrors::PrecisionLoss] if there are more
+    /// significant digits than a Decimal holds.
+    fn try_from(input: &BigNumber) -> Result<Self, Self::Error> {
+        let (mut coefficient, mut scale) = input.coefficient();
+        let fits = |coefficient: &[Digits], scale: usize| -> bool {
+            return scale <= Decimal::MAX_SCALE as usize
+                && magnitude::to_u128(coefficient).is_some_and(|m| m <= MAX_MANTISSA);
+        };
+        while !fits(&coefficient, scale) && scale > 0 && coefficient.last() == Some(&Digits::Zero) {
+            coefficient.pop();
+            scale -= 1;
+        }
+        if !fits(&coefficient, scale) {
+            let limit: Vec<Digits> = magnitude::from_u128(MAX_MANTISSA);
+            if magnitude::compare(&input.integer, &limit) == Ordering::Greater {
+                return Err(MathErrors::Overflow);
+            }
+            return Err(MathErrors::PrecisionLoss);
+        }
+        let mantissa: i128 = magnitude::to_u128(&coefficient).unwrap() as i128;
+        let mantissa: i128 = if input.is_negative() {
+            -mantissa
+        } else {
+            mantissa
+        };
+        return Ok(Decimal::from_i128_with_scale(mantissa, scale as u32));
+    }
+}
+
+impl TryFrom<BigNumber> for Decimal {
+    type Error = MathErrors;
+
+    /// See the conversion from &BigNumber.
+    fn try_from(input: BigNumber) -> Result<Self, Self::Error> {
+        return Decimal::try_from(&input);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_decimal_conversions() {
+        // (BigNumber, Decimal)
+        let test_data = [
+            ("0", Ok("0")),
+            ("1.50", Ok("1.50")),
+            ("-19.99", Ok("-19.99")),
+            (
+                "79228162514264337593543950335",
+                Ok("79228162514264337593543950335"),
+            ),
+            (
+                "-79228162514264337593543950335",
+                Ok("-79228162514264337593543950335"),
+            ),
+            (
+                "0.0000000000000000000000000001",
+                Ok("0.0000000000000000000000000001"),
+            ),
+            (
+                "0.1000000000000000000000000000000",
+                Ok("0.1000000000000000000000000000"),
+            ),
+            (
+                "79228162514264337593543950335.000",
+                Ok("79228162514264337593543950335"),
+            ),
+            ("79228162514264337593543950336", Err(MathErrors::Overflow)),
+            (
+                "-100000000000000000000000000000.5",
+                Err(MathErrors::Overflow),
+            ),
+            (
+                "0.00000000000000000000000000001",
+                Err(MathErrors::PrecisionLoss),
+            ),
+            (
+                "7.9228162514264337593543950336",
+                Err(MathErrors::PrecisionLoss),
+            ),
+            (
+                "1.23456789012345678901234567891",
+                Err(MathErrors::PrecisionLoss),
+            ),
+        ];
+        for (x, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = expected.map(|e| Decimal::from_str(e).unwrap());
+            let result: Result<Decimal, MathErrors> = Decimal::try_from(&x);
+            assert_eq!(result, expected);
+            // Comparing Decimals ignores the scale, the strings do not.
+            if let Ok(y) = expected {
+                assert_eq!(result.unwrap().to_string(), y.to_string());
+                assert_eq!(BigNumber::from(y).to_string(), y.to_string());
+            }
+        }
+        assert_eq!(BigNumber::from(Decimal::NEGATIVE_ONE).to_string(), "-1");
+        assert!(!BigNumber::from(-Decimal::ZERO).is_negative());
+    }
+}
@@ -0,0 +1,321 @@
+#![warn(dead_code)]
+// External imports
+use ::serde::de;
+use ::serde::de::Unexpected;
+use ::serde::ser::SerializeStruct;
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+use std::fmt;
+use std::num::IntErrorKind;
+use std::str::FromStr;
+
+// Inter crate imports
+use super::BigNumber;
+use super::MathErrors;
+
+// serde_json hands numbers over as a struct with this name and a single field
+// of the same name holding the digits when its arbitrary_precision feature is
+// enabled.
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+impl Serialize for BigNumber {
+    /// Writes the number as a string so that no digits are lost. See the
+    /// [as_number] and [as_arbitrary_precision] modules for the alternatives.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return as_string::serialize(self, serializer);
+    }
+}
+
+impl<'de> Deserialize<'de> for BigNumber {
+    /// Accepts strings, integers, floats and serde_json arbitrary precision
+    /// numbers. Strings may use an exponent such as "1.5e-3".
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BigNumber, D::Error> {
+        return deserializer.deserialize_any(BigNumberVisitor);
+    }
+}
+
+/// Reads a string of the form digits, optional fraction and optional
+/// exponent, which covers everything JSON allows for a number.
+///
+/// [MathErrors::Overflow] is returned if the exponent is beyond ±100,000,
+/// see [BigNumber::bounded_shift_decimal], unless the mantissa is zero.
+fn parse_number(s: &str) -> Result<BigNumber, MathErrors> {
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(index) => (&s[..index], &s[index + 1..]),
+        None => (s, "0"),
+    };
+    let mantissa: BigNumber = BigNumber::from_str(mantissa)?;
+    let shifted: Result<BigNumber, MathErrors> = match exponent.parse() {
+        Ok(exponent) => mantissa.bounded_shift_decimal(exponent),
+        Err(error) => match error.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => Err(MathErrors::Overflow),
+            _ => return Err(MathErrors::ParseError),
+        },
+    };
+    // Zero is zero whatever the exponent, so one too large to apply is
+    // dropped.
+    match shifted {
+        Err(MathErrors::Overflow) if mantissa.is_zero() => return Ok(mantissa),
+        result => return result,
+    }
+}
+
+struct BigNumberVisitor;
+
+impl<'de> de::Visitor<'de> for BigNumberVisitor {
+    type Value = BigNumber;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return formatter.write_str("a decimal number or a string holding one");
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<BigNumber, E> {
+        match parse_number(v) {
+            Ok(x) => return Ok(x),
+            Err(MathErrors::Overflow) => {
+                return Err(E::custom(format_args!("exponent out of range in {v}")))
+            }
+            Err(_) => return Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<BigNumber, E> {
+        return Ok(BigNumber::from(v));
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<BigNumber, E> {
+        return Ok(BigNumber::from(v));
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<BigNumber, E> {
+        return Ok(BigNumber::from(v));
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<BigNumber, E> {
+        return Ok(BigNumber::from(v));
+    }
+
+    /// The float is read as the shortest decimal that converts back to it, so
+    /// 0.1 gives 0.1 rather than the exact binary value.
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<BigNumber, E> {
+        if !v.is_finite() {
+            return Err(E::invalid_value(Unexpected::Float(v), &self));
+        }
+        return self.visit_str(&v.to_string());
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<BigNumber, A::Error> {
+        let key: Option<String> = map.next_key()?;
+        if key.as_deref() != Some(NUMBER_TOKEN) {
+            return Err(de::Error::invalid_type(Unexpected::Map, &self));
+        }
+        let digits: String = map.next_value()?;
+        return self.visit_str(&digits);
+    }
+}
+
+/// Writes a BigNumber as a string, which is the default.
+///
+/// Use with `#[serde(with = "ferrous_big_decimal::serde::as_string")]`.
+/// Only strings are accepted when reading, which suits formats that are not
+/// self describing.
+pub mod as_string {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &BigNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_str(&value.to_string());
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigNumber, D::Error> {
+        return deserializer.deserialize_str(BigNumberVisitor);
+    }
+}
+
+/// Writes a BigNumber as a plain number.
+///
+/// Use with `#[serde(with = "ferrous_big_decimal::serde::as_number")]`.
+/// Integers that fit in an i64 or u64 are written exactly, everything else
+/// is written as the nearest f64 and may lose digits.
+pub mod as_number {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &BigNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        let text: String = value.to_string();
+        if value.is_integer() {
+            if let Ok(n) = text.parse::<i64>() {
+                return serializer.serialize_i64(n);
+            }
+            if let Ok(n) = text.parse::<u64>() {
+                return serializer.serialize_u64(n);
+            }
+        }
+        // Every string the Display impl produces is a valid f64 literal.
+        return serializer.serialize_f64(text.parse::<f64>().unwrap());
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigNumber, D::Error> {
+        return deserializer.deserialize_any(BigNumberVisitor);
+    }
+}
+
+/// Writes a BigNumber as a number with every digit kept.
+///
+/// Use with `#[serde(with = "ferrous_big_decimal::serde::as_arbitrary_precision")]`.
+/// This needs the arbitrary_precision feature of serde_json, without it the
+/// number is written as a single entry map.
+pub mod as_arbitrary_precision {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &BigNumber, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut number = serializer.serialize_struct(NUMBER_TOKEN, 1)?;
+        number.serialize_field(NUMBER_TOKEN, &value.to_string())?;
+        return number.end();
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<BigNumber, D::Error> {
+        return deserializer.deserialize_any(BigNumberVisitor);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::serde::de::value::Error;
+    use ::serde::de::IntoDeserializer;
+
+    #[derive(Serialize, Deserialize)]
+    struct Payment {
+        amount: BigNumber,
+        #[serde(with = "as_string")]
+        fee: BigNumber,
+        #[serde(with = "as_number")]
+        rate: BigNumber,
+        #[serde(with = "as_arbitrary_precision")]
+        balance: BigNumber,
+    }
+
+    #[test]
+    fn test_serialize() {
+        let payment = Payment {
+            amount: BigNumber::from_str("-12.50").unwrap(),
+            fee: BigNumber::from_str("0.30").unwrap(),
+            rate: BigNumber::from_str("0.1").unwrap(),
+            balance: BigNumber::from_str("12345678901234567890.123456789").unwrap(),
+        };
+        assert_eq!(
+            serde_json::to_string(&payment).unwrap(),
+            r#"{"amount":"-12.50","fee":"0.30","rate":0.1,"balance":12345678901234567890.123456789}"#
+        );
+
+        // (value, as_number output)
+        let test_data = [
+            ("42", "42"),
+            ("-9223372036854775808", "-9223372036854775808"),
+            ("18446744073709551615", "18446744073709551615"),
+            ("18446744073709551616", "1.8446744073709552e+19"),
+            ("2.50", "2.5"),
+        ];
+        for (value, expected) in test_data {
+            let value = BigNumber::from_str(value).unwrap();
+            let mut output: Vec<u8> = Vec::new();
+            as_number::serialize(&value, &mut serde_json::Serializer::new(&mut output)).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let payment: Payment = serde_json::from_str(
+            r#"{"amount":-12.50,"fee":"0.30","rate":"1.5e-3","balance":1.25E+2}"#,
+        )
+        .unwrap();
+        assert_eq!(payment.amount.to_string(), "-12.50");
+        assert_eq!(payment.fee.to_string(), "0.30");
+        assert_eq!(payment.rate.to_string(), "0.0015");
+        assert_eq!(payment.balance.to_string(), "125");
+
+        // (JSON, expected)
+        let test_data = [
+            (r#""19.99""#, Some("19.99")),
+            ("7", Some("7")),
+            ("-7", Some("-7")),
+            (
+                "340282366920938463463374607431768211456",
+                Some("340282366920938463463374607431768211456"),
+            ),
+            ("0.000001", Some("0.000001")),
+            (r#""1e3""#, Some("1000")),
+            (r#""twelve""#, None),
+            (r#""1.5e""#, None),
+            ("true", None),
+        ];
+        for (json, expected) in test_data {
+            let result: Result<BigNumber, serde_json::Error> = serde_json::from_str(json);
+            assert_eq!(
+                result.ok().map(|x| x.to_string()),
+                expected.map(String::from)
+            );
+        }
+
+        // Exponents that would need an absurd number of digits are refused
+        // rather than tried.
+        let test_data = [
+            r#""1e9223372036854775807""#,
+            r#""1e2000000000""#,
+            r#""1e-2000000000""#,
+            r#""1e99999999999999999999999999999999999999999""#,
+            "1e2000000000",
+        ];
+        for json in test_data {
+            let result: Result<BigNumber, serde_json::Error> = serde_json::from_str(json);
+            assert!(result
+                .unwrap_err()
+                .to_string()
+                .contains("exponent out of range"));
+        }
+
+        // Zero needs no digits whatever its exponent.
+        let test_data = [
+            (r#""0e999999""#, "0"),
+            (r#""0.00e-2000000000""#, "0.00"),
+            (r#""-0e99999999999999999999999999999999999999999""#, "-0"),
+            ("0e999999", "0"),
+        ];
+        for (json, expected) in test_data {
+            let result: BigNumber = serde_json::from_str(json).unwrap();
+            assert_eq!(result.to_string(), expected);
+        }
+
+        // A JSON number is rejected where only strings are expected.
+        let result: Result<Payment, serde_json::Error> =
+            serde_json::from_str(r#"{"amount":"1","fee":0.30,"rate":"1","balance":"1"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_primitives() {
+        let test_data = [
+            (0.1f64, Some("0.1")),
+            (-2.5f64, Some("-2.5")),
+            (1e21f64, Some("1000000000000000000000")),
+            (f64::NAN, None),
+            (f64::INFINITY, None),
+        ];
+        for (value, expected) in test_data {
+            let deserializer = value.into_deserializer();
+            let result: Result<BigNumber, Error> = BigNumber::deserialize(deserializer);
+            assert_eq!(
+                result.ok().map(|x| x.to_string()),
+                expected.map(String::from)
+            );
+        }
+        let deserializer = i128::MIN.into_deserializer();
+        let result: Result<BigNumber, Error> = BigNumber::deserialize(deserializer);
+        assert_eq!(result.unwrap(), BigNumber::from(i128::MIN));
+        let deserializer = u64::MAX.into_deserializer();
+        let result: Result<BigNumber, Error> = BigNumber::deserialize(deserializer);
+        assert_eq!(result.unwrap(), BigNumber::from(u64::MAX));
+    }
+}
@@ -0,0 +1,193 @@
+#![warn(dead_code)]
+// External imports
+use std::cmp;
+use std::ops::Shl;
+use std::ops::Shr;
+
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+/// The largest shift, either way, by an amount read from untrusted input,
+/// see [BigNumber::bounded_shift_decimal]. Real data is nowhere near this,
+/// while an exponent such as 1e2000000000 would exhaust memory.
+pub(crate) const MAX_SHIFT_DIGITS: usize = 100_000;
+
+impl BigNumber {
+    /// Returns self multiplied by 10^n.
+    ///
+    /// Digits are moved between the integer and decimal portions and zeros
+    /// are added where needed. No arithmetic is done, so the cost only
+    /// depends on how many digits move.
+    ///
+    /// Up to |n| zeros are allocated, so a shift by a huge n runs out of
+    /// memory or panics with a capacity overflow. Use [BigNumber::scaleb]
+    /// for shift amounts that are not known to be reasonable.
+    pub fn shift_decimal(self: &Self, n: isize) -> BigNumber {
+        let mut result: BigNumber = self.clone();
+        let count: usize = n.unsigned_abs();
+        if n > 0 {
+            let moved: usize = cmp::min(count, result.decimal.len());
+            result.integer.extend(result.decimal.drain(..moved));
+            result
+                .integer
+                .resize(result.integer.len() + count - moved, Digits::Zero);
+        } else if n < 0 {
+            let moved: usize = cmp::min(count, result.integer.len());
+            let split: usize = result.integer.len() - moved;
+            let mut decimal: Vec<Digits> = vec![Digits::Zero; count - moved];
+            decimal.extend(result.integer.drain(split..));
+            result.decimal.splice(0..0, decimal);
+        }
+        magnitude::trim(&mut result.integer);
+        if result.integer.is_empty() {
+            result.integer.push(Digits::Zero);
+        }
+        if result.is_zero() {
+            result.sign = Sign::Positive;
+        }
+        return result;
+    }
+
+    /// Returns self multiplied by 10^n like [BigNumber::shift_decimal], for
+    /// shift amounts that come from untrusted input.
+    ///
+    /// [MathErrors::Overflow] is returned if n is beyond ±[MAX_SHIFT_DIGITS].
+    /// Only the shift is limited, as the digits of self are already held, so
+    /// the result has at most that many digits more than self.
+    pub(crate) fn bounded_shift_decimal(self: &Self, n: i128) -> Result<BigNumber, MathErrors> {
+        if n.unsigned_abs() > MAX_SHIFT_DIGITS as u128 {
+            return Err(MathErrors::Overflow);
+        }
+        return Ok(self.shift_decimal(n as isize));
+    }
+
+    /// The scaleb operation of the General Decimal Arithmetic Specification,
+    /// self multiplied by 10^exponent.
+    ///
+    /// [MathErrors::DomainError] is returned if exponent is not an integer.
+    /// In place of the specification's limits on exponents,
+    /// [MathErrors::Overflow] is returned if exponent is beyond ±100,000.
+    pub fn scaleb(self: &Self, exponent: &BigNumber) -> Result<BigNumber, MathErrors> {
+        let digits: Vec<Digits> = exponent.integral()?;
+        let n: isize = match magnitude::to_u128(&digits) {
+            Some(n) if n <= MAX_SHIFT_DIGITS as u128 => n as isize,
+            _ => return Err(MathErrors::Overflow),
+        };
+        if exponent.is_negative() {
+            return Ok(self.shift_decimal(-n));
+        }
+        return Ok(self.shift_decimal(n));
+    }
+}
+
+impl Shl<isize> for BigNumber {
+    type Output = BigNumber;
+
+    /// Multiplies self by 10^rhs, see [BigNumber::shift_decimal] for what a
+    /// large rhs costs.
+    fn shl(self, rhs: isize) -> BigNumber {
+        return self.shift_decimal(rhs);
+    }
+}
+
+impl Shl<isize> for &BigNumber {
+    type Output = BigNumber;
+
+    /// Multiplies self by 10^rhs, see [BigNumber::shift_decimal] for what a
+    /// large rhs costs.
+    fn shl(self, rhs: isize) -> BigNumber {
+        return self.shift_decimal(rhs);
+    }
+}
+
+impl Shr<isize> for BigNumber {
+    type Output = BigNumber;
+
+    /// Divides self by 10^rhs, see [BigNumber::shift_decimal] for what a
+    /// large rhs costs.
+    fn shr(self, rhs: isize) -> BigNumber {
+        return (&self).shr(rhs);
+    }
+}
+
+impl Shr<isize> for &BigNumber {
+    type Output = BigNumber;
+
+    /// Divides self by 10^rhs, see [BigNumber::shift_decimal] for what a
+    /// large rhs costs.
+    fn shr(self, rhs: isize) -> BigNumber {
+        match rhs.checked_neg() {
+            Some(n) => return self.shift_decimal(n),
+            // No allocation could hold 2^63 digits, so fail the way integer
+            // shifts by too much do rather than trying.
+            None => panic!("attempt to shift a BigNumber left by more than isize::MAX digits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_shift_decimal() {
+        let test_data = [
+            ("123.45", 2, "12345"),
+            ("123.45", -2, "1.2345"),
+            ("123.45", 5, "12345000"),
+            ("123.45", -5, "0.0012345"),
+            ("123.45", 0, "123.45"),
+            ("-0.05", 2, "-5"),
+            ("-7", -3, "-0.007"),
+            ("0", 4, "0"),
+            ("0.001", 3, "1"),
+        ];
+        for (x, n, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let expected = BigNumber::from_str(expected).unwrap();
+            assert_eq!(x.shift_decimal(n), expected);
+            assert_eq!(&x << n, expected);
+            assert_eq!(x >> -n, expected);
+        }
+        // Shifting keeps every digit, including trailing decimal zeros.
+        let shifted = BigNumber::from_str("1.500").unwrap().shift_decimal(1);
+        assert_eq!(shifted.integer, vec![Digits::One, Digits::Five]);
+        assert_eq!(shifted.decimal, vec![Digits::Zero, Digits::Zero]);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to shift a BigNumber left by more than isize::MAX digits")]
+    fn test_shift_right_by_isize_min() {
+        let _ = BigNumber::from_str("1").unwrap() >> isize::MIN;
+    }
+
+    #[test]
+    fn test_scaleb() {
+        let test_data = [
+            ("7.50", "2", Ok("750")),
+            ("7.50", "-3", Ok("0.0075")),
+            ("-1", "0", Ok("-1")),
+            ("7.50", "1.5", Err(MathErrors::DomainError)),
+            ("1", "100001", Err(MathErrors::Overflow)),
+            ("1", "-100001", Err(MathErrors::Overflow)),
+            ("1", "1000000000000000000", Err(MathErrors::Overflow)),
+            ("1", "100000000000000000000", Err(MathErrors::Overflow)),
+        ];
+        for (x, exponent, expected) in test_data {
+            let x = BigNumber::from_str(x).unwrap();
+            let exponent = BigNumber::from_str(exponent).unwrap();
+            let expected = expected.map(|e| BigNumber::from_str(e).unwrap());
+            assert_eq!(x.scaleb(&exponent), expected);
+        }
+        // The limits themselves are allowed.
+        let x = BigNumber::from_str("1").unwrap();
+        let limit = BigNumber::from_str("100000").unwrap();
+        assert_eq!(x.scaleb(&limit).unwrap().to_string().len(), 100_001);
+        assert_eq!(x.scaleb(&limit.negated()).unwrap().scale(), 100_000);
+    }
+}
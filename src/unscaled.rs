@@ -0,0 +1,172 @@
+#![warn(dead_code)]
+// Inter crate imports
+use super::magnitude;
+use super::BigNumber;
+use super::Digits;
+use super::MathErrors;
+use super::Sign;
+
+// Conversions to and from the unscaled value as big endian two's complement
+// bytes, the way Avro decimal and Parquet DECIMAL store it. The scale is not
+// stored in the bytes and has to come from the schema.
+impl BigNumber {
+    /// Returns self multiplied by 10^scale as big endian two's complement
+    /// bytes, using as few bytes as the value needs like Avro's bytes
+    /// decimal and Parquet's BINARY decimal. Zero is a single zero byte.
+    ///
+    /// [MathErrors::PrecisionLoss] is returned if self has nonzero digits
+    /// beyond the scale.
+    pub fn to_unscaled_be_bytes(self: &Self, scale: usize) -> Result<Vec<u8>, MathErrors> {
+        let shifted: BigNumber = self.shift_decimal(scale as isize);
+        if !shifted.is_integer() {
+            return Err(MathErrors::PrecisionLoss);
+        }
+        let mut result: Vec<u8> = vec![0];
+        result.extend(magnitude::to_be_bytes(&shifted.integer));
+
+        // Negating is inverting every bit and adding one.
+        if shifted.is_negative() {
+            let mut carry: bool = true;
+            for byte in result.iter_mut().rev() {
+                (*byte, carry) = (!*byte).overflowing_add(carry as u8);
+            }
+        }
+        // The leading byte only has to stay when the next one does not carry
+        // the sign in its top bit.
+        let fill: u8 = result[0];
+        if result.len() > 1 && (result[1] & 0x80) == (fill & 0x80) {
+            result.remove(0);
+        }
+        return Ok(result);
+    }
+
+    /// Returns self multiplied by 10^scale as big endian two's complement
+    /// bytes padded to length with the sign, like Avro's fixed decimal and
+    /// Parquet's FIXED_LEN_BYTE_ARRAY decimal.
+    ///
+    /// [MathErrors::PrecisionLoss] is returned if self has nonzero digits
+    /// beyond the scale and [MathErrors::Overflow] if the value needs more
+    /// than length bytes.
+    pub fn to_unscaled_be_bytes_fixed(
+        self: &Self,
+        scale: usize,
+        length: usize,
+    ) -> Result<Vec<u8>, MathErrors> {
+        let bytes: Vec<u8> = self.to_unscaled_be_bytes(scale)?;
+        if bytes.len() > length {
+            return Err(MathErrors::Overflow);
+        }
+        // The sign comes from the bytes, since negative zero encodes as zero.
+        let fill: u8 = if bytes[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let mut result: Vec<u8> = vec![fill; length - bytes.len()];
+        result.extend_from_slice(&bytes);
+        return Ok(result);
+    }
+
+    /// Reads big endian two's complement bytes of any length as an unscaled
+    /// value and divides it by 10^scale, see [BigNumber::to_unscaled_be_bytes].
+    ///
+    /// The result always has the scale, so 150 with a scale of 2 is 1.50.
+    /// No bytes at all are read as zero.
+    pub fn from_unscaled_be_bytes(bytes: &[u8], scale: usize) -> BigNumber {
+        let negative: bool = bytes.first().is_some_and(|byte| byte & 0x80 != 0);
+        let mask: u8 = if negative { 0xFF } else { 0x00 };
+        let data: Vec<u8> = bytes.iter().map(|byte| byte ^ mask).collect();
+        let mut digits: Vec<Digits> = magnitude::from_be_bytes(&data);
+        // Inverting the bits of a negative value gives its magnitude less one.
+        let sign: Sign = if negative {
+            digits = magnitude::add(&digits, &magnitude::from_u128(1));
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        return BigNumber::from_coefficient(sign, digits, scale);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_unscaled_be_bytes() {
+        // (value, scale, bytes)
+        let test_data: [(&str, usize, &[u8]); 12] = [
+            ("0", 0, &[0x00]),
+            ("0.00", 2, &[0x00]),
+            ("1", 0, &[0x01]),
+            ("-1", 0, &[0xFF]),
+            ("1.27", 2, &[0x7F]),
+            ("1.28", 2, &[0x00, 0x80]),
+            ("-1.28", 2, &[0x80]),
+            ("-1.29", 2, &[0xFF, 0x7F]),
+            ("2.56", 2, &[0x01, 0x00]),
+            ("-2.56", 2, &[0xFF, 0x00]),
+            ("-19.99", 2, &[0xF8, 0x31]),
+            (
+                "-170141183460469231731687303715884105728",
+                0,
+                &[
+                    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00,
+                ],
+            ),
+        ];
+        for (value, scale, bytes) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_unscaled_be_bytes(scale).unwrap(), bytes);
+            let decoded = BigNumber::from_unscaled_be_bytes(bytes, scale);
+            assert_eq!(decoded.to_string(), value);
+        }
+
+        // The bytes of any i128 match to_be_bytes once the sign extension
+        // is added back.
+        let test_data = [i128::MAX, i128::MIN, 255, -255, 65536, -65537, 1 << 100];
+        for n in test_data {
+            let x = BigNumber::from_str(&n.to_string()).unwrap();
+            let bytes: Vec<u8> = x.to_unscaled_be_bytes_fixed(0, 16).unwrap();
+            assert_eq!(bytes, n.to_be_bytes());
+        }
+    }
+
+    #[test]
+    fn test_unscaled_be_bytes_fixed() {
+        // (value, scale, length, bytes)
+        let test_data: [(&str, usize, usize, &[u8]); 7] = [
+            ("1.50", 2, 4, &[0x00, 0x00, 0x00, 0x96]),
+            ("-1.50", 2, 4, &[0xFF, 0xFF, 0xFF, 0x6A]),
+            ("2.5", 3, 2, &[0x09, 0xC4]),
+            ("0", 1, 3, &[0x00, 0x00, 0x00]),
+            ("-0.01", 2, 1, &[0xFF]),
+            ("-0", 0, 2, &[0x00, 0x00]),
+            ("-0.00", 2, 4, &[0x00, 0x00, 0x00, 0x00]),
+        ];
+        for (value, scale, length, bytes) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_unscaled_be_bytes_fixed(scale, length).unwrap(), bytes);
+        }
+        let x = BigNumber::from_str("-0").unwrap();
+        assert_eq!(x.to_unscaled_be_bytes(0).unwrap(), [0x00]);
+        let x = BigNumber::from_unscaled_be_bytes(&[0xFF, 0xFF, 0xFF, 0x6A], 2);
+        assert_eq!(x.to_string(), "-1.50");
+        let x = BigNumber::from_unscaled_be_bytes(&[0x00, 0x00, 0x09, 0xC4], 3);
+        assert_eq!(x.to_string(), "2.500");
+        assert_eq!(BigNumber::from_unscaled_be_bytes(&[], 1).to_string(), "0.0");
+    }
+
+    #[test]
+    fn test_unscaled_be_bytes_errors() {
+        // (value, scale, length, error)
+        let test_data = [
+            ("1.234", 2, 4, MathErrors::PrecisionLoss),
+            ("1.28", 2, 1, MathErrors::Overflow),
+            ("-1.29", 2, 1, MathErrors::Overflow),
+            ("65536", 0, 2, MathErrors::Overflow),
+        ];
+        for (value, scale, length, error) in test_data {
+            let x = BigNumber::from_str(value).unwrap();
+            assert_eq!(x.to_unscaled_be_bytes_fixed(scale, length), Err(error));
+        }
+    }
+}